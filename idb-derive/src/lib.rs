@@ -0,0 +1,37 @@
+#![deny(missing_docs, unsafe_code)]
+//! Derive macros for the [`idb`](https://docs.rs/idb) crate.
+//!
+//! This crate is not meant to be used directly. Enable the `derive` feature of `idb` and use the re-exported
+//! `idb::IdbStore` derive macro instead.
+mod store;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `idb::IdbStore` for a struct with named fields.
+///
+/// The object store is declared using the `#[idb(...)]` attribute on the struct:
+///
+/// - `store = "name"`: name of the object store (required)
+/// - `key_path = "field"`: field used as the key path of the object store. The type of the field (or `T` if it is an
+///   `Option<T>`) is used as the type of the keys.
+/// - `key = "Type"`: type of the keys of an object store without a key path
+/// - `auto_increment`: sets the `auto_increment` flag of the object store
+///
+/// Indexes are declared using the `#[idb(...)]` attribute on fields:
+///
+/// - `index` or `index = "name"`: creates an index on the field (named after the field by default)
+/// - `unique`: sets the `unique` flag of the index
+/// - `multi_entry`: sets the `multi_entry` flag of the index. The type of the items of the field (for example, `T` for
+///   a `Vec<T>`) is used as the type of the keys of the index.
+///
+/// For every index, a `<field>_index` function returning a `TypedIndex` is added to the struct. `#[serde(rename)]`
+/// and `#[serde(rename_all)]` attributes are taken into account for the key paths.
+#[proc_macro_derive(IdbStore, attributes(idb))]
+pub fn derive_idb_store(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    store::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
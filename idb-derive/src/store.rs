@@ -0,0 +1,419 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    meta::ParseNestedMeta, spanned::Spanned, Data, DeriveInput, Error, Fields, GenericArgument,
+    Ident, LitStr, PathArguments, Result, Token, Type,
+};
+
+/// Options of the object store given in `#[idb(...)]` attributes of the struct.
+#[derive(Default)]
+struct StoreOptions {
+    store: Option<LitStr>,
+    key_path: Option<LitStr>,
+    key: Option<Type>,
+    auto_increment: bool,
+}
+
+/// Options of an index given in `#[idb(...)]` attributes of a field.
+#[derive(Default)]
+struct IndexOptions {
+    index: bool,
+    name: Option<LitStr>,
+    unique: bool,
+    multi_entry: bool,
+}
+
+/// A field of the struct along with its serialized name.
+struct Field<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    serialized_name: String,
+    index: IndexOptions,
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    name.span(),
+                    "`IdbStore` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                name.span(),
+                "`IdbStore` can only be derived for structs",
+            ))
+        }
+    };
+
+    let options = parse_store_options(&input)?;
+    let rename_all = parse_serde_rename_all(&input)?;
+
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("named field");
+            let serialized_name = match parse_serde_rename(&field.attrs)? {
+                Some(rename) => rename,
+                None => rename_field(&ident.to_string(), rename_all.as_ref()),
+            };
+
+            Ok(Field {
+                ident,
+                ty: &field.ty,
+                serialized_name,
+                index: parse_index_options(field)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let store = options.store.as_ref().ok_or_else(|| {
+        Error::new(
+            name.span(),
+            "missing object store name, add `#[idb(store = \"...\")]` to the struct",
+        )
+    })?;
+
+    let (key_type, key_path) = match (&options.key_path, &options.key) {
+        (Some(key_path), None) => {
+            let field = fields
+                .iter()
+                .find(|field| field.ident == key_path.value().as_str())
+                .ok_or_else(|| {
+                    Error::new(
+                        key_path.span(),
+                        format!(
+                            "key path `{}` does not name a field of `{}`",
+                            key_path.value(),
+                            name
+                        ),
+                    )
+                })?;
+
+            let key_type = option_inner(field.ty).unwrap_or(field.ty);
+            let serialized_name = &field.serialized_name;
+
+            (
+                quote!(#key_type),
+                quote!(::core::option::Option::Some(::idb::KeyPath::new_single(#serialized_name))),
+            )
+        }
+        (None, Some(key)) => (quote!(#key), quote!(::core::option::Option::None)),
+        (Some(key_path), Some(_)) => {
+            return Err(Error::new(
+                key_path.span(),
+                "`key_path` and `key` cannot be used together, the type of the keys is the type of the key path field",
+            ))
+        }
+        (None, None) => {
+            return Err(Error::new(
+                name.span(),
+                "object stores without a `key_path` must specify the type of their keys using `#[idb(key = \"...\")]`",
+            ))
+        }
+    };
+
+    let auto_increment = options
+        .auto_increment
+        .then(|| quote!(.auto_increment(true)));
+
+    let mut indexes = Vec::new();
+    let mut accessors = Vec::new();
+
+    for field in fields.iter().filter(|field| field.index.index) {
+        let index_name = field
+            .index
+            .name
+            .as_ref()
+            .map(LitStr::value)
+            .unwrap_or_else(|| field.serialized_name.clone());
+        let serialized_name = &field.serialized_name;
+        let unique = field.index.unique.then(|| quote!(.unique(true)));
+        let multi_entry = field.index.multi_entry.then(|| quote!(.multi_entry(true)));
+
+        indexes.push(quote! {
+            .add_index(
+                ::idb::builder::IndexBuilder::new(
+                    ::std::string::String::from(#index_name),
+                    ::idb::KeyPath::new_single(#serialized_name),
+                )
+                #unique
+                #multi_entry
+            )
+        });
+
+        let index_key_type = if field.index.multi_entry {
+            item_type(field.ty).unwrap_or(field.ty)
+        } else {
+            field.ty
+        };
+        let accessor = format_ident!("{}_index", field.ident);
+        let doc = format!("Returns the `{index_name}` index of the object store.");
+        let vis = &input.vis;
+
+        accessors.push(quote! {
+            #[doc = #doc]
+            #vis fn #accessor(
+                store: &::idb::TypedObjectStore<<Self as ::idb::IdbStore>::Key, Self>,
+            ) -> ::core::result::Result<::idb::TypedIndex<#index_key_type, Self>, ::idb::Error> {
+                store.index(#index_name)
+            }
+        });
+    }
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let accessors = (!accessors.is_empty()).then(|| {
+        quote! {
+            impl #impl_generics #name #type_generics #where_clause {
+                #(#accessors)*
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::idb::IdbStore for #name #type_generics #where_clause {
+            type Key = #key_type;
+
+            const STORE_NAME: &'static str = #store;
+
+            fn object_store_builder() -> ::idb::builder::ObjectStoreBuilder {
+                ::idb::builder::ObjectStoreBuilder::new(#store)
+                    .key_path(#key_path)
+                    #auto_increment
+                    #(#indexes)*
+            }
+        }
+
+        #accessors
+    })
+}
+
+fn parse_store_options(input: &DeriveInput) -> Result<StoreOptions> {
+    let mut options = StoreOptions::default();
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("idb"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("store") {
+                options.store = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("key_path") {
+                options.key_path = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("key") {
+                let key: LitStr = meta.value()?.parse()?;
+                options.key = Some(key.parse()?);
+            } else if meta.path.is_ident("auto_increment") {
+                options.auto_increment = true;
+            } else {
+                return Err(meta.error("unknown `idb` attribute for object stores"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(options)
+}
+
+fn parse_index_options(field: &syn::Field) -> Result<IndexOptions> {
+    let mut options = IndexOptions::default();
+    let mut flag = None;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("idb"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("index") {
+                options.index = true;
+
+                if meta.input.peek(Token![=]) {
+                    options.name = Some(meta.value()?.parse()?);
+                }
+            } else if meta.path.is_ident("unique") {
+                options.unique = true;
+                flag = Some(meta.path.span());
+            } else if meta.path.is_ident("multi_entry") {
+                options.multi_entry = true;
+                flag = Some(meta.path.span());
+            } else {
+                return Err(meta.error("unknown `idb` attribute for fields"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    if let (false, Some(flag)) = (options.index, flag) {
+        return Err(Error::new(
+            flag,
+            "`unique` and `multi_entry` can only be used along with `index`",
+        ));
+    }
+
+    Ok(options)
+}
+
+/// Returns the value of `#[serde(rename_all = "...")]` on the struct, if any.
+fn parse_serde_rename_all(input: &DeriveInput) -> Result<Option<LitStr>> {
+    let mut rename_all = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                rename_all = Some(parse_serialize_name(&meta)?);
+            } else {
+                skip_meta(&meta)?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    if let Some(rename_all) = &rename_all {
+        if !RENAME_RULES.contains(&rename_all.value().as_str()) {
+            return Err(Error::new(
+                rename_all.span(),
+                format!("unknown `rename_all` rule `{}`", rename_all.value()),
+            ));
+        }
+    }
+
+    Ok(rename_all)
+}
+
+/// Returns the value of `#[serde(rename = "...")]` on a field, if any.
+fn parse_serde_rename(attrs: &[syn::Attribute]) -> Result<Option<String>> {
+    let mut rename = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                rename = Some(parse_serialize_name(&meta)?.value());
+            } else {
+                skip_meta(&meta)?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(rename)
+}
+
+/// Parses `name = "..."` or `name(serialize = "...", deserialize = "...")`, returning the name used for serialization.
+fn parse_serialize_name(meta: &ParseNestedMeta) -> Result<LitStr> {
+    if meta.input.peek(Token![=]) {
+        return meta.value()?.parse();
+    }
+
+    let mut name = None;
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("serialize") {
+            name = Some(meta.value()?.parse()?);
+        } else {
+            skip_meta(&meta)?;
+        }
+
+        Ok(())
+    })?;
+
+    name.ok_or_else(|| meta.error("missing `serialize` name"))
+}
+
+/// Skips over a serde attribute that is not relevant for key paths.
+fn skip_meta(meta: &ParseNestedMeta) -> Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| skip_meta(&meta))?;
+    }
+
+    Ok(())
+}
+
+const RENAME_RULES: &[&str] = &[
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Applies a serde `rename_all` rule to the (snake case) name of a field.
+fn rename_field(name: &str, rename_all: Option<&LitStr>) -> String {
+    let Some(rule) = rename_all.map(LitStr::value) else {
+        return name.to_owned();
+    };
+
+    match rule.as_str() {
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_ascii_uppercase(),
+        "PascalCase" | "camelCase" => {
+            let mut renamed = String::with_capacity(name.len());
+            let mut capitalize = rule == "PascalCase";
+
+            for ch in name.chars() {
+                if ch == '_' {
+                    capitalize = true;
+                } else if capitalize {
+                    renamed.push(ch.to_ascii_uppercase());
+                    capitalize = false;
+                } else {
+                    renamed.push(ch);
+                }
+            }
+
+            renamed
+        }
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_ascii_uppercase(),
+        _ => name.to_owned(),
+    }
+}
+
+/// Returns `T` if the given type is an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(path) if path.path.segments.last()?.ident == "Option" => item_type(ty),
+        _ => None,
+    }
+}
+
+/// Returns `T` if the given type has a single generic type argument `T` (for example, `Vec<T>`).
+fn item_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    let PathArguments::AngleBracketed(arguments) = &path.path.segments.last()?.arguments else {
+        return None;
+    };
+
+    let mut types = arguments.args.iter().filter_map(|argument| match argument {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+
+    match (types.next(), types.next()) {
+        (Some(ty), None) => Some(ty),
+        _ => None,
+    }
+}
@@ -0,0 +1,10 @@
+use idb::IdbStore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, IdbStore)]
+#[idb(store = "employees", auto_increment)]
+struct Employee {
+    name: String,
+}
+
+fn main() {}
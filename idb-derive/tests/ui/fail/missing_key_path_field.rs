@@ -0,0 +1,11 @@
+use idb::IdbStore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, IdbStore)]
+#[idb(store = "employees", key_path = "employee_id")]
+struct Employee {
+    id: u32,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use idb::IdbStore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, IdbStore)]
+#[idb(key_path = "id")]
+struct Employee {
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use idb::IdbStore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, IdbStore)]
+#[idb(store = "employees", key_path = "id")]
+struct Employee {
+    id: u32,
+    #[idb(unique)]
+    email: String,
+}
+
+fn main() {}
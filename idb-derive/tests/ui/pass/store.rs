@@ -0,0 +1,46 @@
+use idb::{IdbStore, TypedIndex, TypedObjectStore};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, IdbStore)]
+#[serde(rename_all = "camelCase")]
+#[idb(store = "employees", key_path = "id", auto_increment)]
+struct Employee {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<u32>,
+    full_name: String,
+    #[idb(index, unique)]
+    email_address: String,
+    #[idb(index = "by_tag", multi_entry)]
+    tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, IdbStore)]
+#[idb(store = "settings", key = "String")]
+struct Setting {
+    #[serde(rename = "VALUE")]
+    #[idb(index)]
+    value: u32,
+}
+
+fn employee_indexes(
+    store: &TypedObjectStore<u32, Employee>,
+) -> (TypedIndex<String, Employee>, TypedIndex<String, Employee>) {
+    (
+        Employee::email_address_index(store).unwrap(),
+        Employee::tags_index(store).unwrap(),
+    )
+}
+
+fn setting_index(store: &TypedObjectStore<String, Setting>) -> TypedIndex<u32, Setting> {
+    Setting::value_index(store).unwrap()
+}
+
+fn main() {
+    let _ = (employee_indexes, setting_index);
+
+    assert_eq!(Employee::STORE_NAME, "employees");
+    assert_eq!(Setting::STORE_NAME, "settings");
+
+    let employees = Employee::object_store_builder();
+    assert_eq!(employees.name(), "employees");
+}
@@ -0,0 +1,258 @@
+use serde_json::Value;
+use wasm_bindgen::JsValue;
+
+use crate::{
+    utils::{from_js_value, to_js_value},
+    CursorDirection, Database, Error, Index, Key, KeyPath, KeyRange, ManagedCursor, ObjectStore,
+    Query, Transaction, TransactionMode, TransactionResult,
+};
+
+use super::{
+    BackendCursor, BackendDatabase, BackendIndex, BackendObjectStore, BackendTransaction, KeyQuery,
+};
+
+impl BackendDatabase for Database {
+    type Transaction = Transaction;
+
+    fn name(&self) -> String {
+        Database::name(self)
+    }
+
+    fn version(&self) -> Result<u32, Error> {
+        Database::version(self)
+    }
+
+    fn store_names(&self) -> Vec<String> {
+        Database::store_names(self)
+    }
+
+    fn transaction(
+        &self,
+        store_names: &[&str],
+        mode: TransactionMode,
+    ) -> Result<Self::Transaction, Error> {
+        Database::transaction(self, store_names, mode)
+    }
+}
+
+impl BackendTransaction for Transaction {
+    type ObjectStore = ObjectStore;
+
+    fn mode(&self) -> Result<TransactionMode, Error> {
+        Transaction::mode(self)
+    }
+
+    fn object_store(&self, name: &str) -> Result<Self::ObjectStore, Error> {
+        Transaction::object_store(self, name)
+    }
+
+    async fn commit(self) -> Result<TransactionResult, Error> {
+        Transaction::commit(self)?.await
+    }
+
+    async fn abort(self) -> Result<TransactionResult, Error> {
+        Transaction::abort(self)?.await
+    }
+}
+
+impl BackendObjectStore for ObjectStore {
+    type Index = Index;
+
+    type Cursor = ManagedCursor;
+
+    fn name(&self) -> String {
+        ObjectStore::name(self)
+    }
+
+    fn key_path(&self) -> Result<Option<KeyPath>, Error> {
+        ObjectStore::key_path(self)
+    }
+
+    fn auto_increment(&self) -> bool {
+        ObjectStore::auto_increment(self)
+    }
+
+    fn index_names(&self) -> Vec<String> {
+        ObjectStore::index_names(self)
+    }
+
+    async fn put(&self, value: &Value, key: Option<&Key>) -> Result<Key, Error> {
+        let key = key.map(JsValue::from);
+        let key = ObjectStore::put(self, &to_js_value(value)?, key.as_ref())?.await?;
+        key.try_into()
+    }
+
+    async fn add(&self, value: &Value, key: Option<&Key>) -> Result<Key, Error> {
+        let key = key.map(JsValue::from);
+        let key = ObjectStore::add(self, &to_js_value(value)?, key.as_ref())?.await?;
+        key.try_into()
+    }
+
+    async fn delete(&self, query: KeyQuery) -> Result<(), Error> {
+        ObjectStore::delete(self, to_required_query(query)?)?.await
+    }
+
+    async fn clear(&self) -> Result<(), Error> {
+        ObjectStore::clear(self)?.await
+    }
+
+    async fn get(&self, query: KeyQuery) -> Result<Option<Value>, Error> {
+        ObjectStore::get(self, to_required_query(query)?)?
+            .await?
+            .map(from_js_value)
+            .transpose()
+    }
+
+    async fn get_all(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Value>, Error> {
+        ObjectStore::get_all(self, to_query(query)?, limit)?
+            .await?
+            .into_iter()
+            .map(from_js_value)
+            .collect()
+    }
+
+    async fn get_all_keys(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Key>, Error> {
+        ObjectStore::get_all_keys(self, to_query(query)?, limit)?
+            .await?
+            .into_iter()
+            .map(Key::try_from)
+            .collect()
+    }
+
+    async fn count(&self, query: Option<KeyQuery>) -> Result<u32, Error> {
+        ObjectStore::count(self, to_query(query)?)?.await
+    }
+
+    fn index(&self, name: &str) -> Result<Self::Index, Error> {
+        ObjectStore::index(self, name)
+    }
+
+    async fn open_cursor(
+        &self,
+        query: Option<KeyQuery>,
+        direction: Option<CursorDirection>,
+    ) -> Result<Option<Self::Cursor>, Error> {
+        let cursor = ObjectStore::open_cursor(self, to_query(query)?, direction)?.await?;
+
+        Ok(cursor.map(|cursor| cursor.into_managed()))
+    }
+}
+
+impl BackendIndex for Index {
+    type Cursor = ManagedCursor;
+
+    fn name(&self) -> String {
+        Index::name(self)
+    }
+
+    fn key_path(&self) -> Result<Option<KeyPath>, Error> {
+        Index::key_path(self)
+    }
+
+    fn unique(&self) -> bool {
+        Index::unique(self)
+    }
+
+    fn multi_entry(&self) -> bool {
+        Index::multi_entry(self)
+    }
+
+    async fn get(&self, query: KeyQuery) -> Result<Option<Value>, Error> {
+        Index::get(self, to_required_query(query)?)?
+            .await?
+            .map(from_js_value)
+            .transpose()
+    }
+
+    async fn get_all(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Value>, Error> {
+        Index::get_all(self, to_query(query)?, limit)?
+            .await?
+            .into_iter()
+            .map(from_js_value)
+            .collect()
+    }
+
+    async fn get_all_keys(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Key>, Error> {
+        Index::get_all_keys(self, to_query(query)?, limit)?
+            .await?
+            .into_iter()
+            .map(Key::try_from)
+            .collect()
+    }
+
+    async fn count(&self, query: Option<KeyQuery>) -> Result<u32, Error> {
+        Index::count(self, to_query(query)?)?.await
+    }
+
+    async fn open_cursor(
+        &self,
+        query: Option<KeyQuery>,
+        direction: Option<CursorDirection>,
+    ) -> Result<Option<Self::Cursor>, Error> {
+        let cursor = Index::open_cursor(self, to_query(query)?, direction)?.await?;
+
+        Ok(cursor.map(|cursor| cursor.into_managed()))
+    }
+}
+
+impl BackendCursor for ManagedCursor {
+    fn key(&self) -> Result<Option<Key>, Error> {
+        ManagedCursor::key(self)
+    }
+
+    fn primary_key(&self) -> Result<Option<Key>, Error> {
+        ManagedCursor::primary_key(self)
+    }
+
+    fn value(&self) -> Result<Option<Value>, Error> {
+        ManagedCursor::value(self)?.map(from_js_value).transpose()
+    }
+
+    async fn next(&mut self) -> Result<(), Error> {
+        ManagedCursor::next(self, None).await
+    }
+
+    async fn advance(&mut self, count: u32) -> Result<(), Error> {
+        ManagedCursor::advance(self, count).await
+    }
+
+    async fn update(&self, value: &Value) -> Result<Key, Error> {
+        ManagedCursor::update(self, &to_js_value(value)?)
+            .await?
+            .try_into()
+    }
+
+    async fn delete(&self) -> Result<(), Error> {
+        ManagedCursor::delete(self).await
+    }
+}
+
+/// Converts a [`KeyQuery`] into a [`Query`]. A query matching all keys is converted into `None`.
+fn to_query(query: Option<KeyQuery>) -> Result<Option<Query>, Error> {
+    query.map_or(Ok(None), |query| query.to_query())
+}
+
+/// Converts a [`KeyQuery`] into a [`Query`] for requests which require one. A query matching all keys is converted
+/// into a key range starting at the smallest possible key (`-Infinity`).
+fn to_required_query(query: KeyQuery) -> Result<Query, Error> {
+    match query.to_query()? {
+        Some(query) => Ok(query),
+        None => Ok(KeyRange::lower_bound(Key::Number(f64::NEG_INFINITY), None)?.into()),
+    }
+}
@@ -0,0 +1,128 @@
+use std::ops::Bound;
+
+use crate::{Error, Key, KeyRange, Query};
+
+/// A backend independent query over keys: either a single key or a range of keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyQuery {
+    lower: Bound<Key>,
+    upper: Bound<Key>,
+}
+
+impl KeyQuery {
+    /// Creates a new [`KeyQuery`] with the given bounds.
+    pub fn new(lower: Bound<Key>, upper: Bound<Key>) -> Self {
+        Self { lower, upper }
+    }
+
+    /// Creates a new [`KeyQuery`] matching all keys.
+    pub fn all() -> Self {
+        Self::new(Bound::Unbounded, Bound::Unbounded)
+    }
+
+    /// Creates a new [`KeyQuery`] matching only the given key.
+    pub fn only(key: impl Into<Key>) -> Self {
+        let key = key.into();
+        Self::new(Bound::Included(key.clone()), Bound::Included(key))
+    }
+
+    /// Creates a new [`KeyQuery`] matching all keys greater than (or equal to, if `open` is `false`) the given key.
+    pub fn lower_bound(key: impl Into<Key>, open: bool) -> Self {
+        Self::new(bound(key.into(), open), Bound::Unbounded)
+    }
+
+    /// Creates a new [`KeyQuery`] matching all keys less than (or equal to, if `open` is `false`) the given key.
+    pub fn upper_bound(key: impl Into<Key>, open: bool) -> Self {
+        Self::new(Bound::Unbounded, bound(key.into(), open))
+    }
+
+    /// Creates a new [`KeyQuery`] matching all keys between the given keys.
+    pub fn bound(
+        lower: impl Into<Key>,
+        upper: impl Into<Key>,
+        lower_open: bool,
+        upper_open: bool,
+    ) -> Self {
+        Self::new(
+            bound(lower.into(), lower_open),
+            bound(upper.into(), upper_open),
+        )
+    }
+
+    /// Returns the lower bound of the query.
+    pub fn lower(&self) -> Bound<&Key> {
+        self.lower.as_ref()
+    }
+
+    /// Returns the upper bound of the query.
+    pub fn upper(&self) -> Bound<&Key> {
+        self.upper.as_ref()
+    }
+
+    /// Returns `true` if the given key matches the query.
+    pub fn contains(&self, key: &Key) -> bool {
+        let above_lower = match &self.lower {
+            Bound::Included(lower) => key >= lower,
+            Bound::Excluded(lower) => key > lower,
+            Bound::Unbounded => true,
+        };
+
+        let below_upper = match &self.upper {
+            Bound::Included(upper) => key <= upper,
+            Bound::Excluded(upper) => key < upper,
+            Bound::Unbounded => true,
+        };
+
+        above_lower && below_upper
+    }
+
+    /// Converts the query into a [`Query`]. Returns `None` if the query matches all keys.
+    pub fn to_query(&self) -> Result<Option<Query>, Error> {
+        let query = match (bound_parts(&self.lower), bound_parts(&self.upper)) {
+            (None, None) => return Ok(None),
+            (Some((lower, false)), Some((upper, false))) if lower == upper => {
+                Query::Key(lower.into())
+            }
+            (Some((lower, lower_open)), None) => {
+                KeyRange::lower_bound(lower, Some(lower_open))?.into()
+            }
+            (None, Some((upper, upper_open))) => {
+                KeyRange::upper_bound(upper, Some(upper_open))?.into()
+            }
+            (Some((lower, lower_open)), Some((upper, upper_open))) => {
+                KeyRange::bound(lower, upper, Some(lower_open), Some(upper_open))?.into()
+            }
+        };
+
+        Ok(Some(query))
+    }
+}
+
+impl Default for KeyQuery {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl From<Key> for KeyQuery {
+    fn from(key: Key) -> Self {
+        Self::only(key)
+    }
+}
+
+fn bound(key: Key, open: bool) -> Bound<Key> {
+    if open {
+        Bound::Excluded(key)
+    } else {
+        Bound::Included(key)
+    }
+}
+
+/// Returns the key of a [`Bound`] and whether it is open, or `None` if it is unbounded.
+fn bound_parts(bound: &Bound<Key>) -> Option<(&Key, bool)> {
+    match bound {
+        Bound::Included(key) => Some((key, false)),
+        Bound::Excluded(key) => Some((key, true)),
+        Bound::Unbounded => None,
+    }
+}
@@ -0,0 +1,251 @@
+use std::ops::Bound;
+
+use serde_json::Value;
+
+use crate::{backend::BackendCursor, CursorDirection, Error, IdbErrorKind, Key};
+
+use super::{
+    memory_error,
+    store_data::{range, IndexData, StoreData},
+    transaction::TransactionHandle,
+    value::extract_key,
+    KeyQuery,
+};
+
+/// A cursor over the records of an object store or an index of an in-memory database.
+#[derive(Debug)]
+pub struct MemoryCursor {
+    handle: TransactionHandle,
+    store: String,
+    /// Name of the index iterated by the cursor (`None` for object store cursors)
+    index: Option<String>,
+    query: KeyQuery,
+    direction: CursorDirection,
+    /// Key, primary key and value of the current record (`None` once the cursor is finished)
+    position: Option<(Key, Key, Value)>,
+}
+
+impl MemoryCursor {
+    /// Opens a cursor positioned at the first matching record. Returns `None` if there are no matching records.
+    pub(crate) fn open(
+        handle: TransactionHandle,
+        store: String,
+        index: Option<String>,
+        query: KeyQuery,
+        direction: CursorDirection,
+    ) -> Result<Option<Self>, Error> {
+        let mut cursor = Self {
+            handle,
+            store,
+            index,
+            query,
+            direction,
+            position: None,
+        };
+
+        cursor.seek()?;
+
+        Ok(cursor.position.is_some().then_some(cursor))
+    }
+
+    /// Moves the cursor to the next record in its direction, relative to its current position.
+    fn seek(&mut self) -> Result<(), Error> {
+        let current = self
+            .position
+            .as_ref()
+            .map(|(key, primary_key, _)| (key.clone(), primary_key.clone()));
+
+        self.position = self.handle.read(&self.store, |store| {
+            let entry = match &self.index {
+                None => seek_records(store, &self.query, self.direction, current),
+                Some(name) => {
+                    let index = store.indexes.get(name).ok_or_else(|| {
+                        memory_error(
+                            IdbErrorKind::InvalidState,
+                            format!("index `{name}` has been deleted"),
+                        )
+                    })?;
+
+                    seek_index(index, &self.query, self.direction, current)
+                }
+            };
+
+            Ok(entry.and_then(|(key, primary_key)| {
+                let value = store.records.get(&primary_key)?.clone();
+                Some((key, primary_key, value))
+            }))
+        })?;
+
+        Ok(())
+    }
+
+    fn primary_key_or_finished(&self) -> Result<&Key, Error> {
+        self.position
+            .as_ref()
+            .map(|(_, primary_key, _)| primary_key)
+            .ok_or(Error::CursorFinished)
+    }
+}
+
+impl BackendCursor for MemoryCursor {
+    fn key(&self) -> Result<Option<Key>, Error> {
+        Ok(self.position.as_ref().map(|(key, _, _)| key.clone()))
+    }
+
+    fn primary_key(&self) -> Result<Option<Key>, Error> {
+        Ok(self
+            .position
+            .as_ref()
+            .map(|(_, primary_key, _)| primary_key.clone()))
+    }
+
+    fn value(&self) -> Result<Option<Value>, Error> {
+        Ok(self.position.as_ref().map(|(_, _, value)| value.clone()))
+    }
+
+    async fn next(&mut self) -> Result<(), Error> {
+        self.primary_key_or_finished()?;
+        self.seek()
+    }
+
+    async fn advance(&mut self, count: u32) -> Result<(), Error> {
+        if count == 0 {
+            return Err(memory_error(
+                IdbErrorKind::Other("TypeError".to_owned()),
+                "cursor cannot be advanced by 0 records",
+            ));
+        }
+
+        self.primary_key_or_finished()?;
+
+        for _ in 0..count {
+            if self.position.is_none() {
+                break;
+            }
+
+            self.seek()?;
+        }
+
+        Ok(())
+    }
+
+    async fn update(&self, value: &Value) -> Result<Key, Error> {
+        let primary_key = self.primary_key_or_finished()?;
+
+        self.handle
+            .write(&self.store, |store, undo| match &store.key_path {
+                Some(key_path) => match extract_key(value, key_path) {
+                    Some(Ok(key)) if &key == primary_key => store.store(value, None, false, undo),
+                    _ => Err(memory_error(
+                        IdbErrorKind::Data,
+                        "key of the updated value does not match the primary key of the cursor",
+                    )),
+                },
+                None => store.store(value, Some(primary_key), false, undo),
+            })
+    }
+
+    async fn delete(&self) -> Result<(), Error> {
+        let primary_key = self.primary_key_or_finished()?;
+
+        self.handle.write(&self.store, |store, undo| {
+            store.delete(primary_key, undo);
+            Ok(())
+        })
+    }
+}
+
+/// Returns the record an object store cursor moves to from its current position (or from the start if `current` is
+/// `None`). Keys of object stores are unique, so unique directions behave like their non-unique counterparts.
+fn seek_records(
+    store: &StoreData,
+    query: &KeyQuery,
+    direction: CursorDirection,
+    current: Option<(Key, Key)>,
+) -> Option<(Key, Key)> {
+    let forward = is_forward(direction);
+    let query = match current {
+        Some((key, _)) => narrow(query, key, forward),
+        None => query.clone(),
+    };
+
+    let mut records = range(&store.records, &query);
+    let (key, _) = if forward {
+        records.next()?
+    } else {
+        records.next_back()?
+    };
+
+    Some((key.clone(), key.clone()))
+}
+
+/// Returns the entry an index cursor moves to from its current position (or from the start if `current` is `None`).
+fn seek_index(
+    index: &IndexData,
+    query: &KeyQuery,
+    direction: CursorDirection,
+    current: Option<(Key, Key)>,
+) -> Option<(Key, Key)> {
+    let forward = is_forward(direction);
+
+    // Non-unique cursors first move to the next record with the same index key
+    if let (Some((key, primary_key)), CursorDirection::Next | CursorDirection::Prev) =
+        (&current, direction)
+    {
+        let primary_keys = index.entries.get(key);
+        let next = primary_keys.and_then(|primary_keys| {
+            let bounds = if forward {
+                (Bound::Excluded(primary_key), Bound::Unbounded)
+            } else {
+                (Bound::Unbounded, Bound::Excluded(primary_key))
+            };
+
+            let mut primary_keys = primary_keys.range::<Key, _>(bounds);
+            if forward {
+                primary_keys.next()
+            } else {
+                primary_keys.next_back()
+            }
+        });
+
+        if let Some(next) = next {
+            return Some((key.clone(), next.clone()));
+        }
+    }
+
+    let query = match current {
+        Some((key, _)) => narrow(query, key, forward),
+        None => query.clone(),
+    };
+
+    let mut entries = range(&index.entries, &query);
+    let (key, primary_keys) = if forward {
+        entries.next()?
+    } else {
+        entries.next_back()?
+    };
+
+    // Iterating backwards, a unique cursor still returns the first record (by primary key) of every key
+    let primary_key = match direction {
+        CursorDirection::Prev => primary_keys.last()?,
+        _ => primary_keys.first()?,
+    };
+
+    Some((key.clone(), primary_key.clone()))
+}
+
+fn is_forward(direction: CursorDirection) -> bool {
+    matches!(
+        direction,
+        CursorDirection::Next | CursorDirection::NextUnique
+    )
+}
+
+/// Narrows the query to the keys after (or before, if not `forward`) the given key.
+fn narrow(query: &KeyQuery, key: Key, forward: bool) -> KeyQuery {
+    if forward {
+        KeyQuery::new(Bound::Excluded(key), query.upper().cloned())
+    } else {
+        KeyQuery::new(query.lower().cloned(), Bound::Excluded(key))
+    }
+}
@@ -0,0 +1,281 @@
+use std::{cell::RefCell, collections::BTreeMap, future::Future, rc::Rc};
+
+use crate::{
+    backend::BackendDatabase, builder::ObjectStoreBuilder, DatabaseInfo, Error, IdbErrorKind,
+    TransactionMode, TransactionResult,
+};
+
+use super::{
+    memory_error,
+    store_data::StoreData,
+    transaction::{ActiveTransactions, MemoryTransaction},
+};
+
+/// State of an in-memory database, shared by all its connections.
+#[derive(Debug)]
+pub(crate) struct DatabaseState {
+    name: String,
+    version: u32,
+    pub(crate) stores: BTreeMap<String, StoreData>,
+    /// `true` while the database is being upgraded
+    upgrading: bool,
+    pub(crate) transactions: ActiveTransactions,
+}
+
+/// Entry point for opening and deleting in-memory databases. Cloned factories share the same databases.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFactory {
+    databases: Rc<RefCell<BTreeMap<String, Rc<RefCell<DatabaseState>>>>>,
+}
+
+impl MemoryFactory {
+    /// Creates a new factory without any databases.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a connection to the database with the given name, creating it if it does not exist.
+    ///
+    /// When `version` is greater than the current version of the database (`0` for new databases), `upgrade` is called
+    /// with the connection, the version change transaction and the old version. If `upgrade` returns an error or aborts
+    /// the transaction, all the changes made during the upgrade are reverted and the error is returned. When `version`
+    /// is `None`, the current version (or `1` for new databases) is used.
+    pub async fn open<F, Fut>(
+        &self,
+        name: &str,
+        version: Option<u32>,
+        upgrade: F,
+    ) -> Result<MemoryDatabase, Error>
+    where
+        F: FnOnce(MemoryDatabase, MemoryTransaction, u32) -> Fut,
+        Fut: Future<Output = Result<(), Error>>,
+    {
+        if version == Some(0) {
+            return Err(memory_error(
+                IdbErrorKind::Other("TypeError".to_owned()),
+                "database version must be greater than 0",
+            ));
+        }
+
+        let existing = self.databases.borrow().get(name).cloned();
+        let is_new = existing.is_none();
+
+        let state = existing.unwrap_or_else(|| {
+            Rc::new(RefCell::new(DatabaseState {
+                name: name.to_owned(),
+                version: 0,
+                stores: BTreeMap::new(),
+                upgrading: false,
+                transactions: ActiveTransactions::default(),
+            }))
+        });
+
+        let old_version = state.borrow().version;
+        let version = version.unwrap_or(old_version.max(1));
+
+        if version < old_version {
+            return Err(memory_error(
+                IdbErrorKind::Version,
+                format!(
+                    "requested version {version} is less than the current version {old_version}"
+                ),
+            ));
+        }
+
+        let database = MemoryDatabase { state };
+
+        if version == old_version {
+            return Ok(database);
+        }
+
+        if is_new {
+            self.databases
+                .borrow_mut()
+                .insert(name.to_owned(), database.state.clone());
+        }
+
+        let transaction =
+            MemoryTransaction::new(database.state.clone(), None, TransactionMode::VersionChange);
+
+        {
+            let mut state = database.state.borrow_mut();
+            state.version = version;
+            state.upgrading = true;
+        }
+
+        let result = upgrade(database.clone(), transaction.clone_handle(), old_version).await;
+
+        database.state.borrow_mut().upgrading = false;
+
+        let result = match (result, transaction.result()) {
+            (Ok(()), Some(TransactionResult::Aborted)) => Err(memory_error(
+                IdbErrorKind::Abort,
+                "version change transaction was aborted",
+            )),
+            (Ok(()), _) => Ok(()),
+            (Err(err), Some(TransactionResult::Aborted)) => Err(err),
+            (Err(err), _) => {
+                transaction.revert();
+                Err(err)
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                if transaction.result().is_none() {
+                    transaction.finish()?;
+                }
+
+                Ok(database)
+            }
+            Err(err) => {
+                database.state.borrow_mut().version = old_version;
+
+                if is_new {
+                    self.databases.borrow_mut().remove(name);
+                }
+
+                Err(err)
+            }
+        }
+    }
+
+    /// Deletes the database with the given name. Existing connections keep their (now detached) data.
+    pub fn delete(&self, name: &str) {
+        self.databases.borrow_mut().remove(name);
+    }
+
+    /// Returns the names and versions of the databases.
+    pub fn databases(&self) -> Vec<DatabaseInfo> {
+        self.databases
+            .borrow()
+            .values()
+            .map(|state| {
+                let state = state.borrow();
+                DatabaseInfo {
+                    name: state.name.clone(),
+                    version: state.version,
+                }
+            })
+            .collect()
+    }
+}
+
+/// A connection to an in-memory database.
+#[derive(Debug, Clone)]
+pub struct MemoryDatabase {
+    state: Rc<RefCell<DatabaseState>>,
+}
+
+impl MemoryDatabase {
+    /// Creates a new object store. Can only be called while the database is being upgraded.
+    pub fn create_object_store(&self, builder: ObjectStoreBuilder) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        state.check_upgrading()?;
+
+        if state.stores.contains_key(builder.name()) {
+            return Err(memory_error(
+                IdbErrorKind::Constraint,
+                format!("object store `{}` already exists", builder.name()),
+            ));
+        }
+
+        let store = StoreData::new(&builder)?;
+        state.stores.insert(builder.name().to_owned(), store);
+
+        Ok(())
+    }
+
+    /// Deletes an object store. Can only be called while the database is being upgraded.
+    pub fn delete_object_store(&self, name: &str) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        state.check_upgrading()?;
+
+        match state.stores.remove(name) {
+            Some(_) => Ok(()),
+            None => Err(memory_error(
+                IdbErrorKind::NotFound,
+                format!("object store `{name}` does not exist"),
+            )),
+        }
+    }
+}
+
+impl BackendDatabase for MemoryDatabase {
+    type Transaction = MemoryTransaction;
+
+    fn name(&self) -> String {
+        self.state.borrow().name.clone()
+    }
+
+    fn version(&self) -> Result<u32, Error> {
+        Ok(self.state.borrow().version)
+    }
+
+    fn store_names(&self) -> Vec<String> {
+        self.state.borrow().stores.keys().cloned().collect()
+    }
+
+    fn transaction(
+        &self,
+        store_names: &[&str],
+        mode: TransactionMode,
+    ) -> Result<Self::Transaction, Error> {
+        if mode == TransactionMode::VersionChange {
+            return Err(memory_error(
+                IdbErrorKind::Other("TypeError".to_owned()),
+                "version change transactions can only be created by opening a database",
+            ));
+        }
+
+        if store_names.is_empty() {
+            return Err(memory_error(
+                IdbErrorKind::InvalidAccess,
+                "transaction scope cannot be empty",
+            ));
+        }
+
+        {
+            let state = self.state.borrow();
+
+            if state.upgrading {
+                return Err(memory_error(
+                    IdbErrorKind::InvalidState,
+                    "database is being upgraded",
+                ));
+            }
+
+            if let Some(name) = store_names
+                .iter()
+                .find(|name| !state.stores.contains_key(**name))
+            {
+                return Err(memory_error(
+                    IdbErrorKind::NotFound,
+                    format!("object store `{name}` does not exist"),
+                ));
+            }
+        }
+
+        let scope: Vec<String> = store_names.iter().map(|name| (*name).to_owned()).collect();
+        self.state.borrow_mut().transactions.check(&scope, mode)?;
+
+        Ok(MemoryTransaction::new(
+            self.state.clone(),
+            Some(scope),
+            mode,
+        ))
+    }
+}
+
+impl DatabaseState {
+    fn check_upgrading(&self) -> Result<(), Error> {
+        if self.upgrading {
+            Ok(())
+        } else {
+            Err(memory_error(
+                IdbErrorKind::InvalidState,
+                "object stores can only be changed while the database is being upgraded",
+            ))
+        }
+    }
+}
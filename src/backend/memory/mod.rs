@@ -0,0 +1,65 @@
+//! A pure Rust, in-memory implementation of the [backend traits](super) for running code natively (for example, in
+//! `cargo test`).
+//!
+//! The in-memory backend follows the IndexedDB semantics for key ordering, key paths, key generators, unique and
+//! multi entry indexes, cursors and transaction aborts. Unlike IndexedDB, transactions are not scheduled: every
+//! transaction is active until it is committed or aborted, and creating a transaction fails with a `TimeoutError` (as
+//! its lock cannot be obtained) while an unfinished transaction with an overlapping scope exists, unless both of them
+//! are read-only.
+//!
+//! ```rust
+//! use idb::{
+//!     backend::{memory::MemoryFactory, BackendDatabase, BackendObjectStore, BackendTransaction, KeyQuery},
+//!     builder::ObjectStoreBuilder,
+//!     Error, KeyPath, TransactionMode,
+//! };
+//! use serde_json::json;
+//!
+//! async fn example() -> Result<(), Error> {
+//!     let factory = MemoryFactory::new();
+//!
+//!     let database = factory
+//!         .open("test", Some(1), |database, _, _| async move {
+//!             database.create_object_store(
+//!                 ObjectStoreBuilder::new("employees")
+//!                     .key_path(Some(KeyPath::new_single("id")))
+//!                     .auto_increment(true),
+//!             )
+//!         })
+//!         .await?;
+//!
+//!     let transaction = database.transaction(&["employees"], TransactionMode::ReadWrite)?;
+//!     let store = transaction.object_store("employees")?;
+//!
+//!     let id = store.add(&json!({ "name": "John Doe" }), None).await?;
+//!     let employee = store.get(KeyQuery::only(id)).await?;
+//!
+//!     assert_eq!(employee, Some(json!({ "id": 1, "name": "John Doe" })));
+//!
+//!     transaction.commit().await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+mod cursor;
+mod database;
+mod object_store;
+mod store_data;
+mod transaction;
+mod value;
+
+pub use self::{
+    cursor::MemoryCursor,
+    database::{MemoryDatabase, MemoryFactory},
+    object_store::{MemoryIndex, MemoryObjectStore},
+    transaction::MemoryTransaction,
+};
+
+use crate::{Error, IdbErrorKind};
+
+use super::KeyQuery;
+
+/// Returns an error raised by the in-memory backend.
+pub(crate) fn memory_error(kind: IdbErrorKind, message: impl Into<String>) -> Error {
+    Error::InMemory(kind, message.into())
+}
@@ -0,0 +1,296 @@
+use serde_json::Value;
+
+use crate::{
+    backend::{BackendIndex, BackendObjectStore},
+    CursorDirection, Error, IdbErrorKind, Key, KeyPath,
+};
+
+use super::{
+    cursor::MemoryCursor,
+    memory_error,
+    store_data::{IndexData, StoreData},
+    transaction::TransactionHandle,
+    KeyQuery,
+};
+
+/// An object store of an in-memory database.
+#[derive(Debug)]
+pub struct MemoryObjectStore {
+    handle: TransactionHandle,
+    name: String,
+}
+
+impl MemoryObjectStore {
+    pub(crate) fn new(handle: TransactionHandle, name: String) -> Self {
+        Self { handle, name }
+    }
+}
+
+impl BackendObjectStore for MemoryObjectStore {
+    type Index = MemoryIndex;
+
+    type Cursor = MemoryCursor;
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn key_path(&self) -> Result<Option<KeyPath>, Error> {
+        Ok(self
+            .handle
+            .metadata(&self.name, |store| store.key_path.clone())
+            .flatten())
+    }
+
+    fn auto_increment(&self) -> bool {
+        self.handle
+            .metadata(&self.name, |store| store.auto_increment)
+            .unwrap_or_default()
+    }
+
+    fn index_names(&self) -> Vec<String> {
+        self.handle
+            .metadata(&self.name, |store| store.indexes.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    async fn put(&self, value: &Value, key: Option<&Key>) -> Result<Key, Error> {
+        self.handle.write(&self.name, |store, undo| {
+            store.store(value, key, false, undo)
+        })
+    }
+
+    async fn add(&self, value: &Value, key: Option<&Key>) -> Result<Key, Error> {
+        self.handle.write(&self.name, |store, undo| {
+            store.store(value, key, true, undo)
+        })
+    }
+
+    async fn delete(&self, query: KeyQuery) -> Result<(), Error> {
+        self.handle.write(&self.name, |store, undo| {
+            let keys: Vec<Key> = store.records(&query).map(|(key, _)| key.clone()).collect();
+
+            for key in keys {
+                store.delete(&key, undo);
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn clear(&self) -> Result<(), Error> {
+        self.handle.write(&self.name, |store, undo| {
+            store.clear(undo);
+            Ok(())
+        })
+    }
+
+    async fn get(&self, query: KeyQuery) -> Result<Option<Value>, Error> {
+        self.handle.read(&self.name, |store| {
+            Ok(store.records(&query).next().map(|(_, value)| value.clone()))
+        })
+    }
+
+    async fn get_all(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Value>, Error> {
+        let query = query.unwrap_or_default();
+
+        self.handle.read(&self.name, |store| {
+            Ok(store
+                .records(&query)
+                .take(take_limit(limit))
+                .map(|(_, value)| value.clone())
+                .collect())
+        })
+    }
+
+    async fn get_all_keys(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Key>, Error> {
+        let query = query.unwrap_or_default();
+
+        self.handle.read(&self.name, |store| {
+            Ok(store
+                .records(&query)
+                .take(take_limit(limit))
+                .map(|(key, _)| key.clone())
+                .collect())
+        })
+    }
+
+    async fn count(&self, query: Option<KeyQuery>) -> Result<u32, Error> {
+        let query = query.unwrap_or_default();
+
+        self.handle
+            .read(&self.name, |store| Ok(count(store.records(&query))))
+    }
+
+    fn index(&self, name: &str) -> Result<Self::Index, Error> {
+        self.handle.read(&self.name, |store| {
+            if store.indexes.contains_key(name) {
+                Ok(())
+            } else {
+                Err(index_not_found(name))
+            }
+        })?;
+
+        Ok(MemoryIndex {
+            handle: self.handle.clone(),
+            store: self.name.clone(),
+            name: name.to_owned(),
+        })
+    }
+
+    async fn open_cursor(
+        &self,
+        query: Option<KeyQuery>,
+        direction: Option<CursorDirection>,
+    ) -> Result<Option<Self::Cursor>, Error> {
+        MemoryCursor::open(
+            self.handle.clone(),
+            self.name.clone(),
+            None,
+            query.unwrap_or_default(),
+            direction.unwrap_or_default(),
+        )
+    }
+}
+
+/// An index of an object store of an in-memory database.
+#[derive(Debug)]
+pub struct MemoryIndex {
+    handle: TransactionHandle,
+    store: String,
+    name: String,
+}
+
+impl MemoryIndex {
+    /// Reads the index and its object store.
+    fn read<R>(
+        &self,
+        f: impl FnOnce(&StoreData, &IndexData) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        self.handle.read(&self.store, |store| {
+            let index = store
+                .indexes
+                .get(&self.name)
+                .ok_or_else(|| index_not_found(&self.name))?;
+
+            f(store, index)
+        })
+    }
+
+    /// Returns the metadata of the index.
+    fn metadata<R: Default>(&self, f: impl FnOnce(&IndexData) -> R) -> R {
+        self.handle
+            .metadata(&self.store, |store| store.indexes.get(&self.name).map(f))
+            .flatten()
+            .unwrap_or_default()
+    }
+}
+
+impl BackendIndex for MemoryIndex {
+    type Cursor = MemoryCursor;
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn key_path(&self) -> Result<Option<KeyPath>, Error> {
+        Ok(self.metadata(|index| Some(index.key_path.clone())))
+    }
+
+    fn unique(&self) -> bool {
+        self.metadata(|index| index.unique)
+    }
+
+    fn multi_entry(&self) -> bool {
+        self.metadata(|index| index.multi_entry)
+    }
+
+    async fn get(&self, query: KeyQuery) -> Result<Option<Value>, Error> {
+        self.read(|store, index| {
+            Ok(index
+                .entries(&query)
+                .next()
+                .and_then(|(_, primary_key)| store.records.get(primary_key))
+                .cloned())
+        })
+    }
+
+    async fn get_all(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Value>, Error> {
+        let query = query.unwrap_or_default();
+
+        self.read(|store, index| {
+            Ok(index
+                .entries(&query)
+                .take(take_limit(limit))
+                .filter_map(|(_, primary_key)| store.records.get(primary_key).cloned())
+                .collect())
+        })
+    }
+
+    async fn get_all_keys(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Key>, Error> {
+        let query = query.unwrap_or_default();
+
+        self.read(|_, index| {
+            Ok(index
+                .entries(&query)
+                .take(take_limit(limit))
+                .map(|(_, primary_key)| primary_key.clone())
+                .collect())
+        })
+    }
+
+    async fn count(&self, query: Option<KeyQuery>) -> Result<u32, Error> {
+        let query = query.unwrap_or_default();
+
+        self.read(|_, index| Ok(count(index.entries(&query))))
+    }
+
+    async fn open_cursor(
+        &self,
+        query: Option<KeyQuery>,
+        direction: Option<CursorDirection>,
+    ) -> Result<Option<Self::Cursor>, Error> {
+        MemoryCursor::open(
+            self.handle.clone(),
+            self.store.clone(),
+            Some(self.name.clone()),
+            query.unwrap_or_default(),
+            direction.unwrap_or_default(),
+        )
+    }
+}
+
+/// Returns the maximum number of records to return for the given limit (`0` means no limit).
+fn take_limit(limit: Option<u32>) -> usize {
+    match limit {
+        Some(limit) if limit > 0 => limit as usize,
+        _ => usize::MAX,
+    }
+}
+
+fn count<I: Iterator>(iter: I) -> u32 {
+    iter.count().try_into().unwrap_or(u32::MAX)
+}
+
+fn index_not_found(name: &str) -> Error {
+    memory_error(
+        IdbErrorKind::NotFound,
+        format!("index `{name}` does not exist"),
+    )
+}
@@ -0,0 +1,357 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Bound,
+};
+
+use serde_json::Value;
+
+use crate::{builder::ObjectStoreBuilder, Error, IdbErrorKind, Key, KeyPath};
+
+use super::{
+    memory_error,
+    value::{extract_key, inject_key, value_at_path, value_to_key, MAX_SAFE_INTEGER},
+    KeyQuery,
+};
+
+/// Records and indexes of an object store.
+#[derive(Debug, Clone)]
+pub(crate) struct StoreData {
+    pub(crate) key_path: Option<KeyPath>,
+    pub(crate) auto_increment: bool,
+    /// Next key to be generated by the key generator
+    next_key: f64,
+    pub(crate) records: BTreeMap<Key, Value>,
+    pub(crate) indexes: BTreeMap<String, IndexData>,
+}
+
+/// Changes made to an object store by a transaction, used to revert them if the transaction is aborted.
+#[derive(Debug, Default)]
+pub(crate) struct UndoLog {
+    /// Key generator before the first change made by the transaction
+    next_key: Option<f64>,
+    /// Keys of the changed records along with their previous values, in the order they were changed
+    records: Vec<(Key, Option<Value>)>,
+}
+
+/// Entries of an index, ordered by index key and then by primary key.
+#[derive(Debug, Clone)]
+pub(crate) struct IndexData {
+    pub(crate) key_path: KeyPath,
+    pub(crate) unique: bool,
+    pub(crate) multi_entry: bool,
+    /// Primary keys of the records for every index key
+    pub(crate) entries: BTreeMap<Key, BTreeSet<Key>>,
+    /// Index keys of every record, for removing the entries of a record without scanning the index
+    keys: BTreeMap<Key, Vec<Key>>,
+}
+
+impl StoreData {
+    pub(crate) fn new(builder: &ObjectStoreBuilder) -> Result<Self, Error> {
+        let key_path = builder.get_key_path().cloned();
+        let auto_increment = builder.get_auto_increment();
+
+        let generator_key_path = matches!(
+            &key_path,
+            Some(KeyPath::Array(_)) | Some(KeyPath::Single(_)) if auto_increment
+        );
+        if generator_key_path
+            && !matches!(&key_path, Some(KeyPath::Single(path)) if !path.is_empty())
+        {
+            return Err(memory_error(
+                IdbErrorKind::InvalidAccess,
+                "auto increment object stores cannot have an empty or array key path",
+            ));
+        }
+
+        let mut store = Self {
+            key_path,
+            auto_increment,
+            next_key: 1.0,
+            records: BTreeMap::new(),
+            indexes: BTreeMap::new(),
+        };
+
+        for index in builder.indexes() {
+            let key_path = index.get_key_path().clone();
+            let multi_entry = index.get_multi_entry();
+
+            if multi_entry && matches!(key_path, KeyPath::Array(_)) {
+                return Err(memory_error(
+                    IdbErrorKind::InvalidAccess,
+                    format!(
+                        "multi entry index `{}` cannot have an array key path",
+                        index.name()
+                    ),
+                ));
+            }
+
+            store.indexes.insert(
+                index.name().to_owned(),
+                IndexData {
+                    key_path,
+                    unique: index.get_unique(),
+                    multi_entry,
+                    entries: BTreeMap::new(),
+                    keys: BTreeMap::new(),
+                },
+            );
+        }
+
+        Ok(store)
+    }
+
+    /// Returns the records whose keys match the query.
+    pub(crate) fn records<'a>(
+        &'a self,
+        query: &'a KeyQuery,
+    ) -> impl DoubleEndedIterator<Item = (&'a Key, &'a Value)> + 'a {
+        range(&self.records, query)
+    }
+
+    /// Adds (or updates, if `no_overwrite` is `false`) a record and returns its key.
+    pub(crate) fn store(
+        &mut self,
+        value: &Value,
+        key: Option<&Key>,
+        no_overwrite: bool,
+        undo: &mut UndoLog,
+    ) -> Result<Key, Error> {
+        let mut value = value.clone();
+        let next_key = self.next_key;
+
+        let key = match (&self.key_path, key) {
+            (Some(_), Some(_)) => {
+                return Err(memory_error(
+                    IdbErrorKind::Data,
+                    "object store uses in-line keys and a key was provided",
+                ))
+            }
+            (Some(key_path), None) => match extract_key(&value, key_path) {
+                Some(key) => key?,
+                None if self.auto_increment => {
+                    let key = self.generate_key()?;
+                    inject_key(&mut value, key_path, &key)?;
+                    key
+                }
+                None => {
+                    return Err(memory_error(
+                        IdbErrorKind::Data,
+                        format!("value does not have a key at key path {key_path:?}"),
+                    ))
+                }
+            },
+            (None, Some(key)) => key.clone(),
+            (None, None) if self.auto_increment => self.generate_key()?,
+            (None, None) => {
+                return Err(memory_error(
+                    IdbErrorKind::Data,
+                    "object store uses out-of-line keys and no key was provided",
+                ))
+            }
+        };
+
+        if !key.is_valid() {
+            return Err(memory_error(
+                IdbErrorKind::Data,
+                format!("{key:?} is not a valid key"),
+            ));
+        }
+
+        if no_overwrite && self.records.contains_key(&key) {
+            return Err(memory_error(
+                IdbErrorKind::Constraint,
+                format!("a record with key {key:?} already exists"),
+            ));
+        }
+
+        let index_keys = self
+            .indexes
+            .iter()
+            .map(|(name, index)| {
+                let keys = index.keys_for(&value);
+
+                if index.unique {
+                    let violated = keys.iter().any(|index_key| {
+                        index.entries.get(index_key).is_some_and(|primary_keys| {
+                            primary_keys.iter().any(|primary_key| primary_key != &key)
+                        })
+                    });
+
+                    if violated {
+                        return Err(memory_error(
+                            IdbErrorKind::Constraint,
+                            format!("unique index `{name}` already contains the key"),
+                        ));
+                    }
+                }
+
+                Ok((name.clone(), keys))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.remove_index_entries(&key);
+        self.add_index_entries(&key, index_keys);
+
+        if self.auto_increment {
+            if let Key::Number(number) = key {
+                if number >= self.next_key {
+                    self.next_key = (number.floor() + 1.0).min(MAX_SAFE_INTEGER + 1.0);
+                }
+            }
+        }
+
+        let previous = self.records.insert(key.clone(), value);
+
+        undo.next_key.get_or_insert(next_key);
+        undo.records.push((key.clone(), previous));
+
+        Ok(key)
+    }
+
+    /// Deletes the record with the given key.
+    pub(crate) fn delete(&mut self, key: &Key, undo: &mut UndoLog) {
+        if let Some(previous) = self.records.remove(key) {
+            self.remove_index_entries(key);
+            undo.records.push((key.clone(), Some(previous)));
+        }
+    }
+
+    /// Deletes all the records.
+    pub(crate) fn clear(&mut self, undo: &mut UndoLog) {
+        let records = std::mem::take(&mut self.records);
+        undo.records
+            .extend(records.into_iter().map(|(key, value)| (key, Some(value))));
+
+        for index in self.indexes.values_mut() {
+            index.entries.clear();
+            index.keys.clear();
+        }
+    }
+
+    /// Reverts the changes recorded in the undo log, restoring the previous values of the changed records. Records
+    /// not changed by the transaction of the log are left untouched.
+    pub(crate) fn revert(&mut self, undo: UndoLog) {
+        for (key, previous) in undo.records.into_iter().rev() {
+            self.remove_index_entries(&key);
+
+            match previous {
+                Some(value) => {
+                    let index_keys = self
+                        .indexes
+                        .iter()
+                        .map(|(name, index)| (name.clone(), index.keys_for(&value)))
+                        .collect();
+
+                    self.add_index_entries(&key, index_keys);
+                    self.records.insert(key, value);
+                }
+                None => {
+                    self.records.remove(&key);
+                }
+            }
+        }
+
+        if let Some(next_key) = undo.next_key {
+            self.next_key = next_key;
+        }
+    }
+
+    fn generate_key(&self) -> Result<Key, Error> {
+        if self.next_key > MAX_SAFE_INTEGER {
+            return Err(memory_error(
+                IdbErrorKind::Constraint,
+                "key generator is exhausted",
+            ));
+        }
+
+        Ok(Key::Number(self.next_key))
+    }
+
+    fn add_index_entries(&mut self, key: &Key, index_keys: Vec<(String, Vec<Key>)>) {
+        for (name, keys) in index_keys {
+            if let Some(index) = self.indexes.get_mut(&name) {
+                if keys.is_empty() {
+                    continue;
+                }
+
+                for index_key in &keys {
+                    index
+                        .entries
+                        .entry(index_key.clone())
+                        .or_default()
+                        .insert(key.clone());
+                }
+
+                index.keys.insert(key.clone(), keys);
+            }
+        }
+    }
+
+    fn remove_index_entries(&mut self, key: &Key) {
+        for index in self.indexes.values_mut() {
+            for index_key in index.keys.remove(key).unwrap_or_default() {
+                if let Some(primary_keys) = index.entries.get_mut(&index_key) {
+                    primary_keys.remove(key);
+
+                    if primary_keys.is_empty() {
+                        index.entries.remove(&index_key);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl IndexData {
+    /// Returns the entries whose index keys match the query.
+    pub(crate) fn entries<'a>(
+        &'a self,
+        query: &'a KeyQuery,
+    ) -> impl DoubleEndedIterator<Item = (&'a Key, &'a Key)> + 'a {
+        range(&self.entries, query).flat_map(|(key, primary_keys)| {
+            primary_keys
+                .iter()
+                .map(move |primary_key| (key, primary_key))
+        })
+    }
+
+    /// Returns the index keys of a record with the given value.
+    fn keys_for(&self, value: &Value) -> Vec<Key> {
+        match &self.key_path {
+            KeyPath::Single(path) if self.multi_entry => match value_at_path(value, path) {
+                Some(Value::Array(values)) => values
+                    .iter()
+                    .filter_map(value_to_key)
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect(),
+                Some(value) => value_to_key(value).into_iter().collect(),
+                None => Vec::new(),
+            },
+            key_path => extract_key(value, key_path)
+                .and_then(Result::ok)
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+/// Returns the entries of the map whose keys match the query.
+pub(crate) fn range<'a, V>(
+    map: &'a BTreeMap<Key, V>,
+    query: &KeyQuery,
+) -> impl DoubleEndedIterator<Item = (&'a Key, &'a V)> + 'a {
+    let empty = match (query.lower(), query.upper()) {
+        (
+            Bound::Included(lower) | Bound::Excluded(lower),
+            Bound::Included(upper) | Bound::Excluded(upper),
+        ) => lower > upper || (lower == upper && !query.contains(lower)),
+        _ => false,
+    };
+
+    // `BTreeMap::range` panics for empty ranges
+    (!empty)
+        .then(|| map.range::<Key, _>((query.lower(), query.upper())))
+        .into_iter()
+        .flatten()
+}
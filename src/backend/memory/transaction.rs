@@ -0,0 +1,275 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    rc::{Rc, Weak},
+};
+
+use crate::{backend::BackendTransaction, Error, IdbErrorKind, TransactionMode, TransactionResult};
+
+use super::{
+    database::DatabaseState,
+    memory_error,
+    object_store::MemoryObjectStore,
+    store_data::{StoreData, UndoLog},
+};
+
+/// A transaction on an in-memory database.
+#[derive(Debug)]
+pub struct MemoryTransaction {
+    handle: TransactionHandle,
+}
+
+/// Shared state of a transaction, held by the transaction and by the object stores, indexes and cursors created from
+/// it.
+#[derive(Debug, Clone)]
+pub(crate) struct TransactionHandle {
+    database: Rc<RefCell<DatabaseState>>,
+    /// Names of the object stores in the scope of the transaction (`None` for version change transactions, whose scope
+    /// is the whole database)
+    scope: Option<Vec<String>>,
+    mode: TransactionMode,
+    state: Rc<RefCell<TransactionState>>,
+}
+
+#[derive(Debug)]
+struct TransactionState {
+    result: Option<TransactionResult>,
+    /// Object stores of the database as they were when the transaction was created (only for version change
+    /// transactions, which can change the object stores themselves and do not run along with other transactions)
+    snapshot: Option<BTreeMap<String, StoreData>>,
+    /// Changes made by the transaction to the records of every object store
+    undo: BTreeMap<String, UndoLog>,
+}
+
+/// Unfinished transactions of a database, used to prevent transactions with overlapping scopes from running at the
+/// same time.
+#[derive(Debug, Default)]
+pub(crate) struct ActiveTransactions(Vec<ActiveTransaction>);
+
+#[derive(Debug)]
+struct ActiveTransaction {
+    scope: Vec<String>,
+    mode: TransactionMode,
+    state: Weak<RefCell<TransactionState>>,
+}
+
+impl ActiveTransactions {
+    /// Returns an error if a transaction with the given scope and mode would conflict with an unfinished transaction,
+    /// i.e., if their scopes overlap and either of them is read-write. Transactions which were dropped without being
+    /// committed or aborted keep their changes and do not conflict.
+    pub(crate) fn check(&mut self, scope: &[String], mode: TransactionMode) -> Result<(), Error> {
+        self.0.retain(|transaction| {
+            transaction
+                .state
+                .upgrade()
+                .is_some_and(|state| state.borrow().result.is_none())
+        });
+
+        let conflict = self.0.iter().find(|transaction| {
+            (mode != TransactionMode::ReadOnly || transaction.mode != TransactionMode::ReadOnly)
+                && transaction.scope.iter().any(|name| scope.contains(name))
+        });
+
+        match conflict {
+            None => Ok(()),
+            Some(transaction) => Err(memory_error(
+                IdbErrorKind::Timeout,
+                format!(
+                    "an unfinished {:?} transaction on {:?} overlaps the scope of the transaction",
+                    transaction.mode, transaction.scope
+                ),
+            )),
+        }
+    }
+}
+
+impl MemoryTransaction {
+    pub(crate) fn new(
+        database: Rc<RefCell<DatabaseState>>,
+        scope: Option<Vec<String>>,
+        mode: TransactionMode,
+    ) -> Self {
+        let snapshot =
+            (mode == TransactionMode::VersionChange).then(|| database.borrow().stores.clone());
+
+        let state = Rc::new(RefCell::new(TransactionState {
+            result: None,
+            snapshot,
+            undo: BTreeMap::new(),
+        }));
+
+        if let Some(scope) = &scope {
+            database
+                .borrow_mut()
+                .transactions
+                .0
+                .push(ActiveTransaction {
+                    scope: scope.clone(),
+                    mode,
+                    state: Rc::downgrade(&state),
+                });
+        }
+
+        Self {
+            handle: TransactionHandle {
+                database,
+                scope,
+                mode,
+                state,
+            },
+        }
+    }
+
+    /// Returns another handle to the same transaction.
+    pub(crate) fn clone_handle(&self) -> Self {
+        Self {
+            handle: self.handle.clone(),
+        }
+    }
+
+    /// Returns the result of the transaction if it is finished.
+    pub(crate) fn result(&self) -> Option<TransactionResult> {
+        self.handle.state.borrow().result
+    }
+
+    /// Marks the transaction as committed.
+    pub(crate) fn finish(&self) -> Result<TransactionResult, Error> {
+        self.handle.check_active()?;
+        self.handle.state.borrow_mut().result = Some(TransactionResult::Committed);
+
+        Ok(TransactionResult::Committed)
+    }
+
+    /// Reverts all the changes made by the transaction and marks it as aborted.
+    pub(crate) fn rollback(&self) -> Result<TransactionResult, Error> {
+        self.handle.check_active()?;
+        self.revert();
+
+        Ok(TransactionResult::Aborted)
+    }
+
+    /// Reverts all the changes made by the transaction and marks it as aborted, even if it is already finished.
+    pub(crate) fn revert(&self) {
+        let mut state = self.handle.state.borrow_mut();
+        let snapshot = state.snapshot.take();
+        let undo = std::mem::take(&mut state.undo);
+        state.result = Some(TransactionResult::Aborted);
+
+        let mut database = self.handle.database.borrow_mut();
+
+        if let Some(snapshot) = snapshot {
+            database.stores = snapshot;
+            return;
+        }
+
+        for (name, undo) in undo {
+            if let Some(store) = database.stores.get_mut(&name) {
+                store.revert(undo);
+            }
+        }
+    }
+}
+
+impl BackendTransaction for MemoryTransaction {
+    type ObjectStore = MemoryObjectStore;
+
+    fn mode(&self) -> Result<TransactionMode, Error> {
+        Ok(self.handle.mode)
+    }
+
+    fn object_store(&self, name: &str) -> Result<Self::ObjectStore, Error> {
+        self.handle.check_active()?;
+
+        let in_scope = self
+            .handle
+            .scope
+            .as_ref()
+            .is_none_or(|scope| scope.iter().any(|store_name| store_name == name));
+
+        if !in_scope || !self.handle.database.borrow().stores.contains_key(name) {
+            return Err(memory_error(
+                IdbErrorKind::NotFound,
+                format!("object store `{name}` is not in the scope of the transaction"),
+            ));
+        }
+
+        Ok(MemoryObjectStore::new(self.handle.clone(), name.to_owned()))
+    }
+
+    async fn commit(self) -> Result<TransactionResult, Error> {
+        self.finish()
+    }
+
+    async fn abort(self) -> Result<TransactionResult, Error> {
+        self.rollback()
+    }
+}
+
+impl TransactionHandle {
+    /// Returns an error if the transaction is finished.
+    fn check_active(&self) -> Result<(), Error> {
+        match self.state.borrow().result {
+            None => Ok(()),
+            Some(_) => Err(memory_error(
+                IdbErrorKind::TransactionInactive,
+                "transaction is finished",
+            )),
+        }
+    }
+
+    /// Returns the metadata of an object store, regardless of the state of the transaction.
+    pub(crate) fn metadata<R>(&self, store: &str, f: impl FnOnce(&StoreData) -> R) -> Option<R> {
+        self.database.borrow().stores.get(store).map(f)
+    }
+
+    /// Reads an object store.
+    pub(crate) fn read<R>(
+        &self,
+        store: &str,
+        f: impl FnOnce(&StoreData) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        self.check_active()?;
+
+        let database = self.database.borrow();
+        let store = database
+            .stores
+            .get(store)
+            .ok_or_else(|| store_not_found(store))?;
+
+        f(store)
+    }
+
+    /// Modifies an object store, recording the changes in the undo log of the transaction.
+    pub(crate) fn write<R>(
+        &self,
+        store: &str,
+        f: impl FnOnce(&mut StoreData, &mut UndoLog) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        self.check_active()?;
+
+        if self.mode == TransactionMode::ReadOnly {
+            return Err(memory_error(
+                IdbErrorKind::ReadOnly,
+                "cannot modify an object store in a read-only transaction",
+            ));
+        }
+
+        let mut database = self.database.borrow_mut();
+        let data = database
+            .stores
+            .get_mut(store)
+            .ok_or_else(|| store_not_found(store))?;
+
+        let mut state = self.state.borrow_mut();
+        let undo = state.undo.entry(store.to_owned()).or_default();
+
+        f(data, undo)
+    }
+}
+
+fn store_not_found(name: &str) -> Error {
+    memory_error(
+        IdbErrorKind::InvalidState,
+        format!("object store `{name}` has been deleted"),
+    )
+}
@@ -0,0 +1,104 @@
+use serde_json::{Map, Number, Value};
+
+use crate::{Error, IdbErrorKind, Key, KeyPath};
+
+use super::memory_error;
+
+/// Largest integer which can be represented exactly as an `f64`.
+pub(crate) const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+/// Converts a JSON value into a key. Returns `None` if the value is not a valid key.
+pub(crate) fn value_to_key(value: &Value) -> Option<Key> {
+    match value {
+        Value::Number(number) => number.as_f64().map(Key::Number),
+        Value::String(string) => Some(Key::String(string.clone())),
+        Value::Array(values) => values
+            .iter()
+            .map(value_to_key)
+            .collect::<Option<Vec<_>>>()
+            .map(Key::Array),
+        _ => None,
+    }
+}
+
+/// Converts a key into a JSON value. Dates are converted into their time values and binary keys into arrays of bytes.
+pub(crate) fn key_to_value(key: &Key) -> Value {
+    match key {
+        Key::Number(number) | Key::Date(number) => number_to_value(*number),
+        Key::String(string) => Value::String(string.clone()),
+        Key::Binary(bytes) => Value::Array(bytes.iter().map(|byte| Value::from(*byte)).collect()),
+        Key::Array(keys) => Value::Array(keys.iter().map(key_to_value).collect()),
+    }
+}
+
+fn number_to_value(number: f64) -> Value {
+    if number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER {
+        Value::from(number as i64)
+    } else {
+        Number::from_f64(number).map_or(Value::Null, Value::Number)
+    }
+}
+
+/// Returns the value at the given (single) key path, or `None` if it does not exist.
+pub(crate) fn value_at_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    if path.is_empty() {
+        return Some(value);
+    }
+
+    path.split('.').try_fold(value, |value, identifier| {
+        value.as_object()?.get(identifier)
+    })
+}
+
+/// Extracts a key from the value using the key path. Returns `None` if the key path does not resolve to a value and
+/// an error if it resolves to something which is not a valid key.
+pub(crate) fn extract_key(value: &Value, key_path: &KeyPath) -> Option<Result<Key, Error>> {
+    let key = match key_path {
+        KeyPath::Single(path) => value_to_key(value_at_path(value, path)?),
+        KeyPath::Array(paths) => paths
+            .iter()
+            .map(|path| value_at_path(value, path).and_then(value_to_key))
+            .collect::<Option<Vec<_>>>()
+            .map(Key::Array),
+    };
+
+    Some(key.ok_or_else(|| {
+        memory_error(
+            IdbErrorKind::Data,
+            format!("value at key path {key_path:?} is not a valid key"),
+        )
+    }))
+}
+
+/// Injects the key into the value at the given (single) key path, creating intermediate objects when missing.
+pub(crate) fn inject_key(value: &mut Value, key_path: &KeyPath, key: &Key) -> Result<(), Error> {
+    let cannot_inject = || {
+        memory_error(
+            IdbErrorKind::Data,
+            format!("cannot inject key at key path {key_path:?}"),
+        )
+    };
+
+    let path = match key_path {
+        KeyPath::Single(path) if !path.is_empty() => path,
+        _ => return Err(cannot_inject()),
+    };
+
+    let mut identifiers = path.split('.').peekable();
+    let mut current = value;
+
+    while let Some(identifier) = identifiers.next() {
+        let object = current.as_object_mut().ok_or_else(cannot_inject)?;
+
+        if identifiers.peek().is_none() {
+            object.insert(identifier.to_owned(), key_to_value(key));
+            return Ok(());
+        }
+
+        current = object
+            .entry(identifier)
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    Ok(())
+}
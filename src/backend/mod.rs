@@ -0,0 +1,210 @@
+//! Backend traits for running the same application code against IndexedDB in the browser and against an in-memory
+//! database natively.
+//!
+//! The traits are implemented by the browser types ([`Database`](crate::Database),
+//! [`Transaction`](crate::Transaction), [`ObjectStore`](crate::ObjectStore), [`Index`](crate::Index) and
+//! [`ManagedCursor`](crate::ManagedCursor)) and by the types of the [`memory`] module. Values are represented as
+//! [`serde_json::Value`]s and keys as [`Key`]s, so code written against these traits does not depend on `JsValue`s.
+//!
+//! ```rust
+//! use idb::{
+//!     backend::{BackendDatabase, BackendObjectStore, BackendTransaction},
+//!     Error, TransactionMode,
+//! };
+//! use serde_json::Value;
+//!
+//! async fn add_employee<D: BackendDatabase>(database: &D, employee: &Value) -> Result<(), Error> {
+//!     let transaction = database.transaction(&["employees"], TransactionMode::ReadWrite)?;
+//!     let store = transaction.object_store("employees")?;
+//!
+//!     store.add(employee, None).await?;
+//!     transaction.commit().await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+mod browser;
+mod key_query;
+pub mod memory;
+
+pub use self::key_query::KeyQuery;
+
+use std::future::Future;
+
+use serde_json::Value;
+
+use crate::{CursorDirection, Error, Key, KeyPath, TransactionMode, TransactionResult};
+
+/// A connection to a database.
+pub trait BackendDatabase {
+    /// Type of transactions on the database.
+    type Transaction: BackendTransaction;
+
+    /// Returns the name of the database.
+    fn name(&self) -> String;
+
+    /// Returns the version of the database.
+    fn version(&self) -> Result<u32, Error>;
+
+    /// Returns the names of the object stores in the database.
+    fn store_names(&self) -> Vec<String>;
+
+    /// Returns a new transaction with the given scope and mode.
+    fn transaction(
+        &self,
+        store_names: &[&str],
+        mode: TransactionMode,
+    ) -> Result<Self::Transaction, Error>;
+}
+
+/// A transaction on a database.
+pub trait BackendTransaction {
+    /// Type of object stores in the transaction.
+    type ObjectStore: BackendObjectStore;
+
+    /// Returns the mode of the transaction.
+    fn mode(&self) -> Result<TransactionMode, Error>;
+
+    /// Returns an object store in the transaction's scope.
+    fn object_store(&self, name: &str) -> Result<Self::ObjectStore, Error>;
+
+    /// Commits the transaction and waits for it to finish.
+    fn commit(self) -> impl Future<Output = Result<TransactionResult, Error>>;
+
+    /// Aborts the transaction, reverting all its changes, and waits for it to finish.
+    fn abort(self) -> impl Future<Output = Result<TransactionResult, Error>>;
+}
+
+/// An object store in a transaction.
+pub trait BackendObjectStore {
+    /// Type of indexes of the object store.
+    type Index: BackendIndex;
+
+    /// Type of cursors over the object store.
+    type Cursor: BackendCursor;
+
+    /// Returns the name of the object store.
+    fn name(&self) -> String;
+
+    /// Returns the key path of the object store.
+    fn key_path(&self) -> Result<Option<KeyPath>, Error>;
+
+    /// Returns `true` if the object store has a key generator.
+    fn auto_increment(&self) -> bool;
+
+    /// Returns the names of the indexes of the object store.
+    fn index_names(&self) -> Vec<String>;
+
+    /// Adds or updates a record with the given value and key and returns its key.
+    fn put(&self, value: &Value, key: Option<&Key>) -> impl Future<Output = Result<Key, Error>>;
+
+    /// Adds a record with the given value and key and returns its key. Fails if a record with the same key exists.
+    fn add(&self, value: &Value, key: Option<&Key>) -> impl Future<Output = Result<Key, Error>>;
+
+    /// Deletes the records matching the given query.
+    fn delete(&self, query: KeyQuery) -> impl Future<Output = Result<(), Error>>;
+
+    /// Deletes all the records of the object store.
+    fn clear(&self) -> impl Future<Output = Result<(), Error>>;
+
+    /// Returns the value of the first record matching the given query.
+    fn get(&self, query: KeyQuery) -> impl Future<Output = Result<Option<Value>, Error>>;
+
+    /// Returns the values of the records matching the given query (up to `limit` if given).
+    fn get_all(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> impl Future<Output = Result<Vec<Value>, Error>>;
+
+    /// Returns the keys of the records matching the given query (up to `limit` if given).
+    fn get_all_keys(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> impl Future<Output = Result<Vec<Key>, Error>>;
+
+    /// Returns the number of records matching the given query.
+    fn count(&self, query: Option<KeyQuery>) -> impl Future<Output = Result<u32, Error>>;
+
+    /// Returns the index with the given name.
+    fn index(&self, name: &str) -> Result<Self::Index, Error>;
+
+    /// Opens a cursor over the records matching the given query. Returns `None` if there are no matching records.
+    fn open_cursor(
+        &self,
+        query: Option<KeyQuery>,
+        direction: Option<CursorDirection>,
+    ) -> impl Future<Output = Result<Option<Self::Cursor>, Error>>;
+}
+
+/// An index of an object store.
+pub trait BackendIndex {
+    /// Type of cursors over the index.
+    type Cursor: BackendCursor;
+
+    /// Returns the name of the index.
+    fn name(&self) -> String;
+
+    /// Returns the key path of the index.
+    fn key_path(&self) -> Result<Option<KeyPath>, Error>;
+
+    /// Returns `true` if the index enforces unique keys.
+    fn unique(&self) -> bool;
+
+    /// Returns `true` if the index adds an entry for every element of array keys.
+    fn multi_entry(&self) -> bool;
+
+    /// Returns the value of the first record whose index key matches the given query.
+    fn get(&self, query: KeyQuery) -> impl Future<Output = Result<Option<Value>, Error>>;
+
+    /// Returns the values of the records whose index keys match the given query (up to `limit` if given).
+    fn get_all(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> impl Future<Output = Result<Vec<Value>, Error>>;
+
+    /// Returns the primary keys of the records whose index keys match the given query (up to `limit` if given).
+    fn get_all_keys(
+        &self,
+        query: Option<KeyQuery>,
+        limit: Option<u32>,
+    ) -> impl Future<Output = Result<Vec<Key>, Error>>;
+
+    /// Returns the number of records whose index keys match the given query.
+    fn count(&self, query: Option<KeyQuery>) -> impl Future<Output = Result<u32, Error>>;
+
+    /// Opens a cursor over the records whose index keys match the given query. Returns `None` if there are no
+    /// matching records.
+    fn open_cursor(
+        &self,
+        query: Option<KeyQuery>,
+        direction: Option<CursorDirection>,
+    ) -> impl Future<Output = Result<Option<Self::Cursor>, Error>>;
+}
+
+/// A cursor over the records of an object store or an index. Once the cursor is finished, its key, primary key and
+/// value are `None`.
+pub trait BackendCursor {
+    /// Returns the key of the record pointed at by the cursor.
+    fn key(&self) -> Result<Option<Key>, Error>;
+
+    /// Returns the primary key of the record pointed at by the cursor.
+    fn primary_key(&self) -> Result<Option<Key>, Error>;
+
+    /// Returns the value of the record pointed at by the cursor.
+    fn value(&self) -> Result<Option<Value>, Error>;
+
+    /// Advances the cursor to the next record.
+    fn next(&mut self) -> impl Future<Output = Result<(), Error>>;
+
+    /// Advances the cursor by the given number of records.
+    fn advance(&mut self, count: u32) -> impl Future<Output = Result<(), Error>>;
+
+    /// Updates the value of the record pointed at by the cursor.
+    fn update(&self, value: &Value) -> impl Future<Output = Result<Key, Error>>;
+
+    /// Deletes the record pointed at by the cursor.
+    fn delete(&self) -> impl Future<Output = Result<(), Error>>;
+}
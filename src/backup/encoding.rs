@@ -0,0 +1,330 @@
+use js_sys::{
+    Array, ArrayBuffer, BigInt, DataView, Date, Function, Map, Object, Reflect, Set, Uint8Array,
+};
+use serde_json::{Map as JsonMap, Number, Value};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::Error;
+
+/// Name of the property holding the type of a tagged value.
+const TYPE: &str = "$type";
+
+/// Names of the `ArrayBuffer` views which can be restored.
+const VIEW_TYPES: [&str; 12] = [
+    "Int8Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "Int16Array",
+    "Uint16Array",
+    "Int32Array",
+    "Uint32Array",
+    "Float32Array",
+    "Float64Array",
+    "BigInt64Array",
+    "BigUint64Array",
+    "DataView",
+];
+
+/// Encodes a structured-cloneable value as JSON.
+///
+/// Booleans, finite numbers, strings, arrays and plain objects are encoded as their JSON counterparts. Other values are
+/// encoded as objects tagged with a `$type` property: `undefined`, non-finite numbers, `BigInt`s, `Date`s,
+/// `ArrayBuffer`s and their views (as hex bytes), `Map`s and `Set`s. Plain objects which have a `$type` property are
+/// tagged as well so that they are not mistaken for tagged values.
+pub(crate) fn encode(value: &JsValue) -> Result<Value, Error> {
+    if value.is_undefined() {
+        return Ok(tagged("undefined", None));
+    }
+
+    if value.is_null() {
+        return Ok(Value::Null);
+    }
+
+    if let Some(boolean) = value.as_bool() {
+        return Ok(Value::Bool(boolean));
+    }
+
+    if let Some(number) = value.as_f64() {
+        return Ok(match to_json_number(number) {
+            Some(number) => Value::Number(number),
+            None => tagged("number", Some(Value::String(non_finite_to_string(number)))),
+        });
+    }
+
+    if let Some(string) = value.as_string() {
+        return Ok(Value::String(string));
+    }
+
+    if value.is_bigint() {
+        let string = BigInt::from(value.clone())
+            .to_string(10)
+            .map_err(|_| Error::UnsupportedBackupValue("BigInt".to_owned()))?;
+        return Ok(tagged("bigint", Some(Value::String(string.into()))));
+    }
+
+    if let Some(date) = value.dyn_ref::<Date>() {
+        let millis = date.get_time();
+        let millis = match to_json_number(millis) {
+            Some(millis) => Value::Number(millis),
+            None => Value::String(non_finite_to_string(millis)),
+        };
+        return Ok(tagged("date", Some(millis)));
+    }
+
+    if let Some(buffer) = value.dyn_ref::<ArrayBuffer>() {
+        let bytes = Uint8Array::new(buffer).to_vec();
+        return Ok(tagged("ArrayBuffer", Some(Value::String(to_hex(&bytes)))));
+    }
+
+    if ArrayBuffer::is_view(value) {
+        let name = constructor_name(value);
+
+        if !VIEW_TYPES.contains(&name.as_str()) {
+            return Err(Error::UnsupportedBackupValue(name));
+        }
+
+        let buffer = Reflect::get(value, &JsValue::from_str("buffer"))
+            .map_err(|_| Error::UnsupportedBackupValue(name.clone()))?;
+        let offset = Reflect::get(value, &JsValue::from_str("byteOffset"))
+            .ok()
+            .and_then(|offset| offset.as_f64())
+            .unwrap_or_default();
+        let length = Reflect::get(value, &JsValue::from_str("byteLength"))
+            .ok()
+            .and_then(|length| length.as_f64())
+            .unwrap_or_default();
+
+        let bytes =
+            Uint8Array::new_with_byte_offset_and_length(&buffer, offset as u32, length as u32)
+                .to_vec();
+        return Ok(tagged(&name, Some(Value::String(to_hex(&bytes)))));
+    }
+
+    if Array::is_array(value) {
+        let array: &Array = value.unchecked_ref();
+        return array
+            .iter()
+            .map(|item| encode(&item))
+            .collect::<Result<_, _>>()
+            .map(Value::Array);
+    }
+
+    if let Some(map) = value.dyn_ref::<Map>() {
+        let mut entries = Vec::new();
+
+        for entry in map.entries() {
+            let entry: Array = entry
+                .map_err(|_| Error::UnsupportedBackupValue("Map".to_owned()))?
+                .into();
+            entries.push(Value::Array(vec![
+                encode(&entry.get(0))?,
+                encode(&entry.get(1))?,
+            ]));
+        }
+
+        return Ok(tagged("Map", Some(Value::Array(entries))));
+    }
+
+    if let Some(set) = value.dyn_ref::<Set>() {
+        let mut items = Vec::new();
+
+        for item in set.values() {
+            let item = item.map_err(|_| Error::UnsupportedBackupValue("Set".to_owned()))?;
+            items.push(encode(&item)?);
+        }
+
+        return Ok(tagged("Set", Some(Value::Array(items))));
+    }
+
+    if let Some(object) = value.dyn_ref::<Object>() {
+        let prototype = Object::get_prototype_of(object);
+
+        if !prototype.is_null() && prototype != Object::get_prototype_of(&Object::new()) {
+            return Err(Error::UnsupportedBackupValue(constructor_name(value)));
+        }
+
+        let mut properties = JsonMap::new();
+
+        for entry in Object::entries(object).iter() {
+            let entry: Array = entry.unchecked_into();
+            let name = entry.get(0).as_string().unwrap_or_default();
+            properties.insert(name, encode(&entry.get(1))?);
+        }
+
+        return Ok(if properties.contains_key(TYPE) {
+            tagged("Object", Some(Value::Object(properties)))
+        } else {
+            Value::Object(properties)
+        });
+    }
+
+    Err(Error::UnsupportedBackupValue(format!("{value:?}")))
+}
+
+/// Decodes a value encoded using [`encode`].
+pub(crate) fn decode(value: &Value) -> Result<JsValue, Error> {
+    match value {
+        Value::Null => Ok(JsValue::NULL),
+        Value::Bool(boolean) => Ok(JsValue::from_bool(*boolean)),
+        Value::Number(number) => number
+            .as_f64()
+            .map(JsValue::from_f64)
+            .ok_or_else(|| invalid("invalid number")),
+        Value::String(string) => Ok(JsValue::from_str(string)),
+        Value::Array(items) => items
+            .iter()
+            .map(decode)
+            .collect::<Result<Array, _>>()
+            .map(Into::into),
+        Value::Object(properties) => match properties.get(TYPE) {
+            None => decode_object(properties),
+            Some(Value::String(tag)) => decode_tagged(tag, properties.get("value")),
+            Some(_) => Err(invalid("invalid type tag")),
+        },
+    }
+}
+
+fn decode_object(properties: &JsonMap<String, Value>) -> Result<JsValue, Error> {
+    let object = Object::new();
+
+    for (name, value) in properties {
+        Reflect::set(&object, &JsValue::from_str(name), &decode(value)?)
+            .map_err(|_| invalid("failed to set property"))?;
+    }
+
+    Ok(object.into())
+}
+
+fn decode_tagged(tag: &str, value: Option<&Value>) -> Result<JsValue, Error> {
+    match (tag, value) {
+        ("undefined", _) => Ok(JsValue::UNDEFINED),
+        ("number", Some(Value::String(number))) => parse_non_finite(number).map(JsValue::from_f64),
+        ("bigint", Some(Value::String(number))) => BigInt::new(&JsValue::from_str(number))
+            .map(Into::into)
+            .map_err(|_| invalid("invalid BigInt")),
+        ("date", Some(Value::Number(millis))) => Ok(Date::new(&JsValue::from_f64(
+            millis.as_f64().ok_or_else(|| invalid("invalid date"))?,
+        ))
+        .into()),
+        ("date", Some(Value::String(millis))) => {
+            Ok(Date::new(&JsValue::from_f64(parse_non_finite(millis)?)).into())
+        }
+        ("ArrayBuffer", Some(Value::String(hex))) => {
+            Ok(Uint8Array::from(from_hex(hex)?.as_slice()).buffer().into())
+        }
+        ("DataView", Some(Value::String(hex))) => {
+            let buffer = Uint8Array::from(from_hex(hex)?.as_slice()).buffer();
+            Ok(DataView::new(&buffer, 0, buffer.byte_length() as usize).into())
+        }
+        (name, Some(Value::String(hex))) if VIEW_TYPES.contains(&name) => {
+            let buffer = Uint8Array::from(from_hex(hex)?.as_slice()).buffer();
+            let constructor: Function = Reflect::get(&js_sys::global(), &JsValue::from_str(name))
+                .ok()
+                .and_then(|constructor| constructor.dyn_into().ok())
+                .ok_or_else(|| invalid("unsupported array buffer view"))?;
+
+            Reflect::construct(&constructor, &Array::of1(&buffer))
+                .map_err(|_| invalid("invalid array buffer view"))
+        }
+        ("Map", Some(Value::Array(entries))) => {
+            let map = Map::new();
+
+            for entry in entries {
+                match entry {
+                    Value::Array(entry) if entry.len() == 2 => {
+                        map.set(&decode(&entry[0])?, &decode(&entry[1])?);
+                    }
+                    _ => return Err(invalid("invalid map entry")),
+                }
+            }
+
+            Ok(map.into())
+        }
+        ("Set", Some(Value::Array(items))) => {
+            let set = Set::new(&JsValue::UNDEFINED);
+
+            for item in items {
+                set.add(&decode(item)?);
+            }
+
+            Ok(set.into())
+        }
+        ("Object", Some(Value::Object(properties))) => decode_object(properties),
+        _ => Err(invalid("invalid tagged value")),
+    }
+}
+
+fn tagged(tag: &str, value: Option<Value>) -> Value {
+    let mut object = JsonMap::new();
+    object.insert(TYPE.to_owned(), Value::String(tag.to_owned()));
+
+    if let Some(value) = value {
+        object.insert("value".to_owned(), value);
+    }
+
+    Value::Object(object)
+}
+
+fn constructor_name(value: &JsValue) -> String {
+    Reflect::get(value, &JsValue::from_str("constructor"))
+        .and_then(|constructor| Reflect::get(&constructor, &JsValue::from_str("name")))
+        .ok()
+        .and_then(|name| name.as_string())
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+/// Converts a finite number to JSON, without a fractional part if it is a safe integer (other than `-0`).
+fn to_json_number(number: f64) -> Option<Number> {
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+    if number.fract() == 0.0
+        && number.abs() <= MAX_SAFE_INTEGER
+        && !(number == 0.0 && number.is_sign_negative())
+    {
+        Some(Number::from(number as i64))
+    } else {
+        Number::from_f64(number)
+    }
+}
+
+fn non_finite_to_string(number: f64) -> String {
+    if number.is_nan() {
+        "NaN".to_owned()
+    } else if number.is_sign_positive() {
+        "Infinity".to_owned()
+    } else {
+        "-Infinity".to_owned()
+    }
+}
+
+fn parse_non_finite(number: &str) -> Result<f64, Error> {
+    match number {
+        "NaN" => Ok(f64::NAN),
+        "Infinity" => Ok(f64::INFINITY),
+        "-Infinity" => Ok(f64::NEG_INFINITY),
+        _ => Err(invalid("invalid non-finite number")),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    if !hex.len().is_multiple_of(2) {
+        return Err(invalid("invalid hex bytes"));
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| invalid("invalid hex bytes"))
+        })
+        .collect()
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::InvalidBackup(reason.to_owned())
+}
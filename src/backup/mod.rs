@@ -0,0 +1,216 @@
+//! Export and import of whole databases as [JSON Lines](https://jsonlines.org/).
+//!
+//! The first line of a backup is a header containing the [`DatabaseSchema`] of the database. Every following line is a
+//! record of an object store with its key, primary key and value:
+//!
+//! ```text
+//! {"format":"idb-backup","version":1,"schema":{"name":"test","version":1,"object_stores":[...]}}
+//! {"store":"employees","key":1,"primary_key":1,"value":{"id":1,"name":"John Doe"}}
+//! ```
+//!
+//! Values which cannot be represented in JSON (`undefined`, non-finite numbers, `BigInt`s, `Date`s, `ArrayBuffer`s,
+//! typed arrays, `DataView`s, `Map`s and `Set`s) are encoded as objects tagged with a `$type` property, for example,
+//! `{"$type":"date","value":1700000000000}`, so that they are restored faithfully. Exporting any other non-plain
+//! object (for example, a `Blob`) fails with [`Error::UnsupportedBackupValue`].
+//!
+//! ```rust
+//! use idb::{backup, Database, Error};
+//!
+//! async fn copy_database(database: &Database) -> Result<Database, Error> {
+//!     let backup = backup::export(database).await?;
+//!     backup::import_as("copy", &backup).await
+//! }
+//! ```
+mod encoding;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    builder::DatabaseBuilder, BatchItem, Database, DatabaseSchema, Error, TransactionMode,
+};
+
+/// Value of the `format` field of the header of a backup.
+const FORMAT: &str = "idb-backup";
+
+/// Version of the backup format.
+const VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    format: String,
+    version: u32,
+    schema: DatabaseSchema,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordLine {
+    store: String,
+    key: Value,
+    primary_key: Value,
+    value: Value,
+}
+
+/// Exports the schema and all the records of the database as JSON Lines.
+///
+/// The records are read with cursors in a single read-only transaction, so the backup is a consistent snapshot of the
+/// database.
+pub async fn export(database: &Database) -> Result<String, Error> {
+    let schema = database.schema()?;
+    let store_names = schema
+        .object_stores
+        .iter()
+        .map(|object_store| object_store.name.clone())
+        .collect::<Vec<_>>();
+
+    let mut backup = to_line(&Header {
+        format: FORMAT.to_owned(),
+        version: VERSION,
+        schema,
+    })?;
+
+    if store_names.is_empty() {
+        return Ok(backup);
+    }
+
+    let transaction = database.transaction(&store_names, TransactionMode::ReadOnly)?;
+
+    for store_name in store_names {
+        let store = transaction.object_store(&store_name)?;
+
+        let Some(cursor) = store.open_cursor(None, None)?.await? else {
+            continue;
+        };
+        let mut cursor = cursor.into_managed();
+
+        while let (Some(key), Some(primary_key)) = (cursor.key()?, cursor.primary_key()?) {
+            let value = cursor.value()?.unwrap_or_default();
+
+            backup.push_str(&to_line(&RecordLine {
+                store: store_name.clone(),
+                key: encoding::encode(&key.into())?,
+                primary_key: encoding::encode(&primary_key.into())?,
+                value: encoding::encode(&value)?,
+            })?);
+
+            cursor.next(None).await?;
+        }
+    }
+
+    transaction.await?;
+
+    Ok(backup)
+}
+
+/// Imports a backup created by [`export`] into a new database with the name and version of the backed up database.
+///
+/// The schema is created using a [`DatabaseBuilder`] and the records are written in a single read-write transaction,
+/// so either all of them are imported or none of them is. An existing database with the same name is upgraded to the
+/// schema of the backup, which fails if its version is higher than the version of the backup.
+pub async fn import(backup: &str) -> Result<Database, Error> {
+    import_backup(None, backup).await
+}
+
+/// Imports a backup created by [`export`] like [`import`], but into a database with the given name.
+pub async fn import_as(name: &str, backup: &str) -> Result<Database, Error> {
+    import_backup(Some(name), backup).await
+}
+
+async fn import_backup(name: Option<&str>, backup: &str) -> Result<Database, Error> {
+    let mut lines = backup.lines().filter(|line| !line.trim().is_empty());
+
+    let header: Header = from_line(lines.next().ok_or_else(|| invalid("missing header"))?)?;
+
+    if header.format != FORMAT {
+        return Err(invalid("unknown format"));
+    }
+
+    if header.version != VERSION {
+        return Err(invalid("unsupported version"));
+    }
+
+    let mut schema = header.schema;
+
+    if let Some(name) = name {
+        schema.name = name.to_owned();
+    }
+
+    // Records are grouped by object store, in the order in which they were exported
+    let mut records: Vec<(String, Vec<BatchItem>)> = Vec::new();
+
+    for line in lines {
+        let record: RecordLine = from_line(line)?;
+
+        let object_store = schema
+            .object_store(&record.store)
+            .ok_or_else(|| invalid("record of unknown object store"))?;
+
+        let key = if object_store.key_path.is_some() {
+            None
+        } else {
+            Some(encoding::decode(&record.primary_key)?)
+        };
+        let item = BatchItem::new(encoding::decode(&record.value)?, key);
+
+        match records.last_mut() {
+            Some((store, items)) if *store == record.store => items.push(item),
+            _ => records.push((record.store, vec![item])),
+        }
+    }
+
+    let database = DatabaseBuilder::from(schema.clone()).build().await?;
+
+    if records.is_empty() {
+        return Ok(database);
+    }
+
+    let store_names = schema
+        .object_stores
+        .iter()
+        .map(|object_store| object_store.name.as_str())
+        .collect::<Vec<_>>();
+
+    let result = database
+        .with_transaction(
+            &store_names,
+            TransactionMode::ReadWrite,
+            |transaction| async move {
+                for (store_name, items) in records {
+                    let result = transaction
+                        .object_store(&store_name)?
+                        .put_all(items)
+                        .await?;
+
+                    if let Some(failure) = result.failures.into_iter().next() {
+                        return Err(failure.error);
+                    }
+                }
+
+                Ok(())
+            },
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok(database),
+        Err(err) => {
+            database.close();
+            Err(err)
+        }
+    }
+}
+
+fn to_line<T: Serialize>(value: &T) -> Result<String, Error> {
+    let mut line =
+        serde_json::to_string(value).map_err(|err| Error::InvalidBackup(err.to_string()))?;
+    line.push('\n');
+    Ok(line)
+}
+
+fn from_line<T: serde::de::DeserializeOwned>(line: &str) -> Result<T, Error> {
+    serde_json::from_str(line).map_err(|err| Error::InvalidBackup(err.to_string()))
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::InvalidBackup(reason.to_owned())
+}
@@ -1,10 +1,29 @@
-use std::collections::HashSet;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashSet},
+    fmt,
+    future::{poll_fn, Future, IntoFuture},
+    pin::Pin,
+    rc::Rc,
+    task::Poll,
+    time::Duration,
+};
 
 use indexmap::{IndexMap, IndexSet};
+use js_sys::Reflect;
+use tokio::sync::oneshot;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::IdbVersionChangeEvent;
 
-use crate::{Database, DatabaseEvent as _, Error, Event as _, Factory, Request as _};
+use crate::{
+    event::VersionChangeEvent,
+    request::{futures::OpenDatabaseRequestFuture, OpenDatabaseRequest},
+    utils::Timeout,
+    Database, DatabaseEvent as _, DatabaseSchema, Error, Event as _, Factory, ObjectStoreSchema,
+    Request as _,
+};
 
-use super::ObjectStoreBuilder;
+use super::{Migration, ObjectStoreBuilder, SchemaOperation, SchemaPlan, UpgradeStep};
 
 /// Builder for databases.
 #[derive(Debug)]
@@ -15,6 +34,20 @@ pub struct DatabaseBuilder {
     /// Maps the new name to the old name and the builder.
     object_stores_to_rename: IndexMap<String, (String, ObjectStoreBuilder)>,
     object_stores_to_remove: IndexSet<String>,
+    migrations: BTreeMap<u32, Migration>,
+    blocked_callback: Option<VersionCallback<u32>>,
+    blocked_timeout: Option<Duration>,
+    version_change_callback: Option<VersionCallback<Option<u32>>>,
+    allow_destructive: bool,
+}
+
+/// A callback receiving the current and the requested version of the database.
+struct VersionCallback<N>(Box<dyn FnMut(u32, N)>);
+
+impl<N> fmt::Debug for VersionCallback<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VersionCallback").finish_non_exhaustive()
+    }
 }
 
 impl DatabaseBuilder {
@@ -26,10 +59,15 @@ impl DatabaseBuilder {
             object_stores: Default::default(),
             object_stores_to_rename: Default::default(),
             object_stores_to_remove: Default::default(),
+            migrations: Default::default(),
+            blocked_callback: None,
+            blocked_timeout: None,
+            version_change_callback: None,
+            allow_destructive: true,
         }
     }
 
-    /// Sets the version of the database.
+    /// Sets the version of the database. Defaults to the highest migration version if migrations are added.
     pub fn version(mut self, version: u32) -> Self {
         self.version = Some(version);
         self
@@ -50,8 +88,9 @@ impl DatabaseBuilder {
         self
     }
 
-    /// Renames the object store with the given name to the new given name.
-    pub fn rename_object_store(mut self, old_name: &str, new_name: &str) -> Self {
+    /// Renames the object store with the given name to the new given name. Returns an [`Error`] if no object store
+    /// with the given name was added to the builder.
+    pub fn rename_object_store(mut self, old_name: &str, new_name: &str) -> Result<Self, Error> {
         let mut old_name = old_name.to_owned();
 
         let mut previous_object_store = self
@@ -67,84 +106,558 @@ impl DatabaseBuilder {
                     },
                 )
             })
-            .expect("cannot rename an object store which does not exist");
+            .ok_or_else(|| Error::ObjectStoreNotFound(JsValue::from_str(&old_name)))?;
 
         previous_object_store.set_name(new_name);
 
         self.object_stores_to_rename
             .insert(new_name.to_owned(), (old_name, previous_object_store));
 
+        Ok(self)
+    }
+
+    /// Adds a migration. Replaces any migration previously added for the same version.
+    ///
+    /// When the database is upgraded, migrations with versions greater than the old version (and not greater than the
+    /// new version) are run in ascending order of their versions, after the object stores of this builder are created,
+    /// renamed or updated. Object stores which are not part of this builder are only deleted after all the migrations
+    /// have finished, so migrations can still move data out of them.
+    pub fn add_migration(mut self, migration: Migration) -> Self {
+        self.migrations.insert(migration.version(), migration);
+        self
+    }
+
+    /// Sets a callback which is called with the current and the requested version of the database when the upgrade is
+    /// blocked by other open connections which did not close in response to the `versionchange` event.
+    pub fn on_blocked<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(u32, u32) + 'static,
+    {
+        self.blocked_callback = Some(VersionCallback(Box::new(callback)));
+        self
+    }
+
+    /// Sets how long [`DatabaseBuilder::build`] waits for a blocked upgrade before failing with
+    /// [`Error::UpgradeBlocked`]. By default, it waits until the other connections are closed.
+    ///
+    /// If the upgrade is unblocked after the timeout, it is aborted and the database is left at its current version.
+    pub fn blocked_timeout(mut self, timeout: Duration) -> Self {
+        self.blocked_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a callback which is called when the built connection closes itself because another connection requested
+    /// to upgrade (or delete) the database. The callback receives the current and the requested version of the
+    /// database (`None` if the database is being deleted), for example, to ask the user to reload the page. The callback
+    /// is dropped along with the built [`Database`].
+    pub fn on_version_change<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(u32, Option<u32>) + 'static,
+    {
+        self.version_change_callback = Some(VersionCallback(Box::new(callback)));
+        self
+    }
+
+    /// Sets whether upgrades may delete object stores or delete (or recreate) indexes. Defaults to `true`.
+    ///
+    /// When set to `false`, [`DatabaseBuilder::build`] aborts upgrades with [destructive](SchemaOperation::is_destructive)
+    /// operations and returns an [`Error::DestructiveUpgrade`] listing them.
+    pub fn allow_destructive(mut self, allow_destructive: bool) -> Self {
+        self.allow_destructive = allow_destructive;
         self
     }
 
+    /// Compares the builder against the current schema of the database and returns the operations
+    /// [`DatabaseBuilder::build`] would perform, without changing the database.
+    pub async fn plan(&self) -> Result<SchemaPlan, Error> {
+        let factory = Factory::new()?;
+
+        let exists = factory
+            .databases()
+            .await?
+            .iter()
+            .any(|database| database.name == self.name);
+
+        let (old_version, object_stores) = if exists {
+            // Opening without a version never upgrades the database
+            let database = factory.open(&self.name, None)?.await?;
+            let schema = database.schema();
+            database.close();
+
+            let schema = schema?;
+            (schema.version, schema.object_stores)
+        } else {
+            (0, Vec::new())
+        };
+
+        let new_version = self
+            .version
+            .or_else(|| self.migrations.keys().next_back().copied())
+            .unwrap_or(old_version.max(1));
+
+        let operations = if new_version > old_version {
+            self.operations(&object_stores, old_version, Some(new_version))
+        } else {
+            Vec::new()
+        };
+
+        Ok(SchemaPlan {
+            old_version,
+            new_version,
+            operations,
+        })
+    }
+
     /// Builds the database.
+    ///
+    /// If any step of the upgrade fails, the upgrade transaction is aborted and an [`Error::UpgradeFailed`] naming the
+    /// failed step is returned. If the upgrade is blocked for longer than the
+    /// [blocked timeout](DatabaseBuilder::blocked_timeout), an [`Error::UpgradeBlocked`] is returned.
+    ///
+    /// The returned connection closes itself as soon as another connection requests to upgrade or delete the database
+    /// so that it never blocks it.
     pub async fn build(mut self) -> Result<Database, Error> {
         let factory = Factory::new()?;
-        let mut request = factory.open(&self.name, self.version)?;
+        let version = self
+            .version
+            .or_else(|| self.migrations.keys().next_back().copied());
+        let mut request = factory.open(&self.name, version)?;
+
+        let blocked_timeout = self.blocked_timeout;
+        let mut blocked_callback = self.blocked_callback.take();
+        let mut version_change_callback = self.version_change_callback.take();
+
+        let (blocked_sender, blocked_receiver) = oneshot::channel();
+        request.on_blocked(move |event| {
+            let old = event.old_version().unwrap_or_default();
+            let new = event.new_version().ok().flatten().unwrap_or(old);
+
+            if let Some(callback) = blocked_callback.as_mut() {
+                (callback.0)(old, new);
+            }
+
+            let _ = blocked_sender.send((old, new));
+        });
+
+        let upgrade_error = Rc::new(RefCell::new(None));
+        let upgrade_needed_error = upgrade_error.clone();
+
+        let timed_out = Rc::new(Cell::new(false));
+        let upgrade_timed_out = timed_out.clone();
 
         request.on_upgrade_needed(move |event| {
-            let request = event.target().expect("open database request");
-            let database = event.database().expect("database");
+            if upgrade_timed_out.get() {
+                // `build` already returned an `Error::UpgradeBlocked`
+                if let Ok(request) = event.target() {
+                    abort_upgrade(&request);
+                }
+                return;
+            }
+
+            if let Err(err) = self.upgrade(&event, upgrade_needed_error.clone()) {
+                if let Ok(request) = event.target() {
+                    abort_upgrade(&request);
+                }
+
+                *upgrade_needed_error.borrow_mut() = Some(err);
+            }
+        });
+
+        let mut request = request.into_future();
+
+        let result = match blocked_timeout {
+            None => request.await,
+            Some(timeout) => {
+                match wait_unless_blocked(&mut request, blocked_receiver, timeout).await {
+                    Ok(result) => result,
+                    Err(err) => {
+                        timed_out.set(true);
 
-            let mut existing_store_names = database.store_names();
+                        // Close the connection if the request succeeds after all
+                        wasm_bindgen_futures::spawn_local(async move {
+                            if let Ok(database) = request.await {
+                                database.close();
+                            }
+                        });
 
-            // Explicitly removed object stores
-            for store_to_remove in self.object_stores_to_remove.iter() {
-                if existing_store_names.contains(store_to_remove) {
-                    database
-                        .delete_object_store(store_to_remove)
-                        .expect("object store deletion");
+                        return Err(err);
+                    }
                 }
             }
+        };
 
-            let stores_to_retain = self
-                .object_stores
-                .keys()
-                .cloned()
-                .chain(self.object_stores_to_rename.keys().cloned())
-                .collect::<HashSet<_>>();
-
-            // For each store to rename, rename if it exists locally, just add it otherwise
-            for (new_name, (old_name, store)) in self.object_stores_to_rename.into_iter() {
-                if !existing_store_names.contains(&old_name) {
-                    self.object_stores.insert(new_name, store);
-                } else {
-                    request
-                        .transaction()
-                        .expect("transaction")
-                        .object_store(&old_name)
-                        .expect("object store")
-                        .to_owned()
-                        .set_name(&new_name);
-                    existing_store_names = database.store_names()
+        if let Some(err) = upgrade_error.take() {
+            if let Ok(database) = result {
+                database.close();
+            }
+
+            return Err(err);
+        }
+
+        let mut database = result?;
+
+        // Added as a listener (instead of `Database::on_version_change`) so that it cannot be replaced by handlers set
+        // on the built connection. The listener is kept by the connection and removed when it is dropped.
+        let listener = database.add_version_change_listener(move |event| {
+            if let Ok(database) = event.database() {
+                database.close();
+            }
+
+            if let Some(callback) = version_change_callback.as_mut() {
+                if let Some(event) = event.dyn_ref::<IdbVersionChangeEvent>() {
+                    let event = VersionChangeEvent::from(event.clone());
+
+                    if let Ok(old) = event.old_version() {
+                        (callback.0)(old, event.new_version().ok().flatten());
+                    }
                 }
             }
+        });
+
+        match listener {
+            Ok(listener) => database.set_version_change_listener(listener),
+            Err(err) => {
+                database.close();
+                return Err(err);
+            }
+        }
+
+        Ok(database)
+    }
+
+    /// Returns the operations performed by [`DatabaseBuilder::upgrade`] on a database with the given object stores.
+    fn operations(
+        &self,
+        object_stores: &[ObjectStoreSchema],
+        old_version: u32,
+        new_version: Option<u32>,
+    ) -> Vec<SchemaOperation> {
+        let mut operations = Vec::new();
+        let mut existing_stores = object_stores.iter().collect::<Vec<_>>();
+
+        // Explicitly removed object stores
+        existing_stores.retain(|existing_store| {
+            let remove = self.object_stores_to_remove.contains(&existing_store.name);
 
-            for object_store in self.object_stores.into_values() {
-                object_store
-                    .apply(&database, &request)
-                    .expect("object store creation");
+            if remove {
+                operations.push(SchemaOperation::DeleteObjectStore(
+                    existing_store.name.clone(),
+                ));
             }
 
-            // Object stores removed implicitly by not adding them
-            for db_store_name in existing_store_names {
-                if !stores_to_retain.contains(&db_store_name)
-                    && !self.object_stores_to_remove.contains(&db_store_name)
+            !remove
+        });
+
+        let mut renamed_store_names = Vec::new();
+        let mut stores_to_apply = self.object_stores.values().collect::<Vec<_>>();
+
+        for (new_name, (old_name, store)) in self.object_stores_to_rename.iter() {
+            if existing_stores
+                .iter()
+                .any(|existing_store| existing_store.name == *old_name)
+            {
+                operations.push(SchemaOperation::RenameObjectStore {
+                    from: old_name.clone(),
+                    to: new_name.clone(),
+                });
+                renamed_store_names.push(old_name);
+            } else {
+                stores_to_apply.push(store);
+            }
+        }
+
+        for store in stores_to_apply {
+            let existing_store = existing_stores
+                .iter()
+                .find(|existing_store| existing_store.name == store.name());
+
+            let Some(existing_store) = existing_store else {
+                operations.push(SchemaOperation::CreateObjectStore(store.name().to_owned()));
+                operations.extend(store.indexes().iter().map(|index| {
+                    SchemaOperation::CreateIndex {
+                        object_store: store.name().to_owned(),
+                        index: index.name().to_owned(),
+                    }
+                }));
+                continue;
+            };
+
+            for index in store.indexes() {
+                let object_store = store.name().to_owned();
+                let name = index.name().to_owned();
+
+                match existing_store.index(index.name()) {
+                    None => operations.push(SchemaOperation::CreateIndex {
+                        object_store,
+                        index: name,
+                    }),
+                    Some(existing_index) if !index.matches(existing_index) => {
+                        operations.push(SchemaOperation::RecreateIndex {
+                            object_store,
+                            index: name,
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            for existing_index in existing_store.indexes.iter() {
+                if !store
+                    .indexes()
+                    .iter()
+                    .any(|index| index.name() == existing_index.name)
                 {
-                    database
-                        .delete_object_store(&db_store_name)
-                        .expect("object store deletion");
+                    operations.push(SchemaOperation::DeleteIndex {
+                        object_store: store.name().to_owned(),
+                        index: existing_index.name.clone(),
+                    });
                 }
             }
-        });
+        }
+
+        operations.extend(
+            self.migrations
+                .keys()
+                .filter(|version| {
+                    **version > old_version
+                        && new_version.is_none_or(|new_version| **version <= new_version)
+                })
+                .map(|version| SchemaOperation::RunMigration(*version)),
+        );
 
-        let mut database = request.await?;
+        // Object stores removed implicitly by not adding them
+        operations.extend(
+            existing_stores
+                .iter()
+                .filter(|existing_store| {
+                    !renamed_store_names.contains(&&existing_store.name)
+                        && !self.object_stores.contains_key(&existing_store.name)
+                        && !self
+                            .object_stores_to_rename
+                            .contains_key(&existing_store.name)
+                })
+                .map(|existing_store| {
+                    SchemaOperation::DeleteObjectStore(existing_store.name.clone())
+                }),
+        );
 
-        database.on_version_change(|event| {
-            let database = event.database().expect("database");
-            database.close();
+        operations
+    }
+
+    /// Applies the schema of the builder in the upgrade transaction and starts the migrations. Errors of the migrations
+    /// are written to `migration_error`.
+    fn upgrade(
+        mut self,
+        event: &VersionChangeEvent,
+        migration_error: Rc<RefCell<Option<Error>>>,
+    ) -> Result<(), Error> {
+        let request = event.target()?;
+        let database = event.database()?;
+
+        let old_version = event.old_version()?;
+        let new_version = event.new_version()?;
+
+        if !self.allow_destructive {
+            let transaction = request.transaction().ok_or(Error::TransactionNotFound)?;
+            let object_stores = database
+                .store_names()
+                .iter()
+                .map(|name| transaction.object_store(name)?.schema())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let destructive_operations = self
+                .operations(&object_stores, old_version, new_version)
+                .into_iter()
+                .filter(SchemaOperation::is_destructive)
+                .collect::<Vec<_>>();
+
+            if !destructive_operations.is_empty() {
+                return Err(Error::DestructiveUpgrade(destructive_operations));
+            }
+        }
+
+        let mut existing_store_names = database.store_names();
+
+        // Explicitly removed object stores
+        for store_to_remove in self.object_stores_to_remove.iter() {
+            if existing_store_names.contains(store_to_remove) {
+                database
+                    .delete_object_store(store_to_remove)
+                    .map_err(|err| {
+                        UpgradeStep::DeleteObjectStore(store_to_remove.clone()).failed(err)
+                    })?;
+            }
+        }
+
+        let stores_to_retain = self
+            .object_stores
+            .keys()
+            .cloned()
+            .chain(self.object_stores_to_rename.keys().cloned())
+            .collect::<HashSet<_>>();
+
+        // For each store to rename, rename if it exists locally, just add it otherwise
+        for (new_name, (old_name, store)) in self.object_stores_to_rename.into_iter() {
+            if !existing_store_names.contains(&old_name) {
+                self.object_stores.insert(new_name, store);
+            } else {
+                rename_object_store(&request, &old_name, &new_name).map_err(|err| {
+                    UpgradeStep::RenameObjectStore {
+                        from: old_name.clone(),
+                        to: new_name.clone(),
+                    }
+                    .failed(err)
+                })?;
+                existing_store_names = database.store_names()
+            }
+        }
+
+        for object_store in self.object_stores.into_values() {
+            object_store.apply(&database, &request)?;
+        }
+
+        // Object stores removed implicitly by not adding them
+        let stores_to_delete = existing_store_names
+            .into_iter()
+            .filter(|db_store_name| {
+                !stores_to_retain.contains(db_store_name)
+                    && !self.object_stores_to_remove.contains(db_store_name)
+            })
+            .collect::<Vec<_>>();
+
+        let migrations = self
+            .migrations
+            .into_values()
+            .filter(|migration| {
+                migration.version() > old_version
+                    && new_version.is_none_or(|new_version| migration.version() <= new_version)
+            })
+            .collect::<Vec<_>>();
+
+        if migrations.is_empty() {
+            return delete_object_stores(&database, stores_to_delete);
+        }
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = run_migrations(&request, migrations, stores_to_delete, old_version).await;
+
+            if let Err(err) = result {
+                abort_upgrade(&request);
+                *migration_error.borrow_mut() = Some(err);
+            }
         });
 
-        Ok(database)
+        Ok(())
+    }
+}
+
+impl From<DatabaseSchema> for DatabaseBuilder {
+    fn from(schema: DatabaseSchema) -> Self {
+        schema.object_stores.into_iter().fold(
+            Self::new(&schema.name).version(schema.version),
+            |builder, object_store| builder.add_object_store(object_store.into()),
+        )
+    }
+}
+
+/// Waits for the open request to finish. Returns an [`Error::UpgradeBlocked`] if the request is blocked for longer
+/// than the given timeout.
+async fn wait_unless_blocked(
+    request: &mut OpenDatabaseRequestFuture,
+    mut blocked_receiver: oneshot::Receiver<(u32, u32)>,
+    timeout: Duration,
+) -> Result<Result<Database, Error>, Error> {
+    let mut blocked = None;
+
+    poll_fn(|cx| {
+        if let Poll::Ready(result) = Pin::new(&mut *request).poll(cx) {
+            return Poll::Ready(Ok(result));
+        }
+
+        if blocked.is_none() {
+            if let Poll::Ready(Ok(versions)) = Pin::new(&mut blocked_receiver).poll(cx) {
+                let (timeout_sender, timeout_receiver) = oneshot::channel();
+                let timer = Timeout::new(timeout, move || {
+                    let _ = timeout_sender.send(());
+                })?;
+
+                blocked = Some((versions, timeout_receiver, timer));
+            }
+        }
+
+        if let Some(((old, new), timeout_receiver, _)) = blocked.as_mut() {
+            if Pin::new(timeout_receiver).poll(cx).is_ready() {
+                return Poll::Ready(Err(Error::UpgradeBlocked {
+                    old: *old,
+                    new: *new,
+                }));
+            }
+        }
+
+        Poll::Pending
+    })
+    .await
+}
+
+/// Renames an object store in the upgrade transaction of the given request.
+fn rename_object_store(
+    request: &OpenDatabaseRequest,
+    old_name: &str,
+    new_name: &str,
+) -> Result<(), Error> {
+    let object_store = request
+        .transaction()
+        .ok_or(Error::TransactionNotFound)?
+        .object_store(old_name)?;
+
+    // `IDBObjectStore.name` setter throws on failure, so it is set via `Reflect` to catch the exception
+    Reflect::set(
+        &JsValue::from(object_store),
+        &JsValue::from_str("name"),
+        &JsValue::from_str(new_name),
+    )
+    .map_err(Error::ObjectStoreRenameFailed)?;
+
+    Ok(())
+}
+
+/// Deletes the given object stores.
+fn delete_object_stores(database: &Database, store_names: Vec<String>) -> Result<(), Error> {
+    for store_name in store_names {
+        database
+            .delete_object_store(&store_name)
+            .map_err(|err| UpgradeStep::DeleteObjectStore(store_name.clone()).failed(err))?;
+    }
+
+    Ok(())
+}
+
+/// Runs the migrations in order and then deletes the given object stores.
+async fn run_migrations(
+    request: &OpenDatabaseRequest,
+    migrations: Vec<Migration>,
+    stores_to_delete: Vec<String>,
+    old_version: u32,
+) -> Result<(), Error> {
+    for migration in migrations {
+        let version = migration.version();
+
+        let transaction = request.transaction().ok_or(Error::TransactionNotFound)?;
+        let database = transaction.database();
+
+        migration
+            .run(database, transaction, old_version)
+            .await
+            .map_err(|err| UpgradeStep::Migration(version).failed(err))?;
+    }
+
+    delete_object_stores(&request.database()?, stores_to_delete)
+}
+
+/// Aborts the upgrade transaction of the given request.
+fn abort_upgrade(request: &OpenDatabaseRequest) {
+    if let Some(transaction) = request.transaction() {
+        // The transaction may already be finished, in which case there is nothing to abort
+        let _ = transaction.abort();
     }
 }
@@ -1,4 +1,6 @@
-use crate::{Error, IndexParams, KeyPath, ObjectStore};
+use crate::{Error, IndexParams, IndexSchema, KeyPath, ObjectStore};
+
+use super::UpgradeStep;
 
 /// Builder for object store indexes.
 #[derive(Debug)]
@@ -25,6 +27,28 @@ impl IndexBuilder {
         &self.name
     }
 
+    #[cfg(feature = "memory")]
+    pub(crate) fn get_key_path(&self) -> &KeyPath {
+        &self.key_path
+    }
+
+    #[cfg(feature = "memory")]
+    pub(crate) fn get_unique(&self) -> bool {
+        self.unique.unwrap_or_default()
+    }
+
+    #[cfg(feature = "memory")]
+    pub(crate) fn get_multi_entry(&self) -> bool {
+        self.multi_entry.unwrap_or_default()
+    }
+
+    /// Returns `true` if the existing index has the definition of this builder.
+    pub(crate) fn matches(&self, schema: &IndexSchema) -> bool {
+        schema.key_path == self.key_path
+            && schema.unique == self.unique.unwrap_or_default()
+            && schema.multi_entry == self.multi_entry.unwrap_or_default()
+    }
+
     /// Sets the `unique` flag.
     pub fn unique(mut self, unique: bool) -> Self {
         self.unique = Some(unique);
@@ -39,15 +63,26 @@ impl IndexBuilder {
 
     /// Applies the index to the given object store.
     pub(crate) fn apply(self, object_store: &ObjectStore) -> Result<(), Error> {
+        let create_step = || UpgradeStep::CreateIndex {
+            object_store: object_store.name(),
+            index: self.name.clone(),
+        };
+
         if let Ok(existing_index) = object_store.index(&self.name) {
-            let indexes_equal = existing_index.key_path()?.as_ref() == Some(&self.key_path)
-                && Some(existing_index.unique()) == self.unique
-                && Some(existing_index.multi_entry()) == self.multi_entry;
-            if indexes_equal {
+            let schema = existing_index
+                .schema()
+                .map_err(|err| create_step().failed(err))?;
+            if self.matches(&schema) {
                 // skip re-creating the same index
                 return Ok(());
             } else {
-                object_store.delete_index(&self.name)?;
+                object_store.delete_index(&self.name).map_err(|err| {
+                    UpgradeStep::DeleteIndex {
+                        object_store: object_store.name(),
+                        index: self.name.clone(),
+                    }
+                    .failed(err)
+                })?;
             }
         }
         let mut params = IndexParams::new();
@@ -60,8 +95,18 @@ impl IndexBuilder {
             params.multi_entry(multi_entry);
         }
 
-        object_store.create_index(&self.name, self.key_path, Some(params))?;
+        object_store
+            .create_index(&self.name, self.key_path, Some(params))
+            .map_err(|err| create_step().failed(err))?;
 
         Ok(())
     }
 }
+
+impl From<IndexSchema> for IndexBuilder {
+    fn from(schema: IndexSchema) -> Self {
+        Self::new(schema.name, schema.key_path)
+            .unique(schema.unique)
+            .multi_entry(schema.multi_entry)
+    }
+}
@@ -0,0 +1,58 @@
+use std::{fmt, future::Future, pin::Pin};
+
+use crate::{Database, Error, Transaction};
+
+type MigrationFuture = Pin<Box<dyn Future<Output = Result<(), Error>>>>;
+
+type MigrationFn = Box<dyn FnOnce(Database, Transaction, u32) -> MigrationFuture>;
+
+/// A migration step which is run when a database is upgraded to (or past) its version.
+///
+/// A migration receives the [`Database`], the upgrade [`Transaction`] and the version of the database before the
+/// upgrade started. It can change the schema as well as read and rewrite existing data (for example, by iterating over
+/// a cursor).
+pub struct Migration {
+    version: u32,
+    migration: MigrationFn,
+}
+
+impl Migration {
+    /// Creates a new instance of [`Migration`] for given version.
+    ///
+    /// The returned future must only await requests made on the given upgrade [`Transaction`]. Awaiting anything else
+    /// (a timer, a network request, etc.) lets the transaction auto-commit before the migration is finished.
+    pub fn new<F, Fut>(version: u32, migration: F) -> Self
+    where
+        F: FnOnce(Database, Transaction, u32) -> Fut + 'static,
+        Fut: Future<Output = Result<(), Error>> + 'static,
+    {
+        Self {
+            version,
+            migration: Box::new(move |database, transaction, old_version| {
+                Box::pin(migration(database, transaction, old_version))
+            }),
+        }
+    }
+
+    /// Returns the version of the migration.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub(crate) async fn run(
+        self,
+        database: Database,
+        transaction: Transaction,
+        old_version: u32,
+    ) -> Result<(), Error> {
+        (self.migration)(database, transaction, old_version).await
+    }
+}
+
+impl fmt::Debug for Migration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migration")
+            .field("version", &self.version)
+            .finish_non_exhaustive()
+    }
+}
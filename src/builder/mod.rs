@@ -1,8 +1,16 @@
 //! Contains the builder for the database.
 mod database_builder;
 mod index;
+mod migration;
 mod object_store;
+mod schema_plan;
+mod upgrade_step;
 
 pub use self::{
-    database_builder::DatabaseBuilder, index::IndexBuilder, object_store::ObjectStoreBuilder,
+    database_builder::DatabaseBuilder,
+    index::IndexBuilder,
+    migration::Migration,
+    object_store::ObjectStoreBuilder,
+    schema_plan::{SchemaOperation, SchemaPlan},
+    upgrade_step::UpgradeStep,
 };
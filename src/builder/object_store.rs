@@ -1,8 +1,11 @@
 use std::collections::HashSet;
 
-use crate::{request::OpenDatabaseRequest, Database, Error, KeyPath, ObjectStoreParams, Request};
+use crate::{
+    request::OpenDatabaseRequest, Database, Error, KeyPath, ObjectStoreParams, ObjectStoreSchema,
+    Request,
+};
 
-use super::IndexBuilder;
+use super::{IndexBuilder, UpgradeStep};
 
 /// Builder for object stores.
 #[derive(Debug)]
@@ -29,6 +32,20 @@ impl ObjectStoreBuilder {
         &self.name
     }
 
+    #[cfg(feature = "memory")]
+    pub(crate) fn get_auto_increment(&self) -> bool {
+        self.auto_increment.unwrap_or_default()
+    }
+
+    #[cfg(feature = "memory")]
+    pub(crate) fn get_key_path(&self) -> Option<&KeyPath> {
+        self.key_path.as_ref()
+    }
+
+    pub(crate) fn indexes(&self) -> &[IndexBuilder] {
+        &self.indexes
+    }
+
     pub(crate) fn set_name(&mut self, name: &str) {
         self.name = name.to_owned()
     }
@@ -63,11 +80,10 @@ impl ObjectStoreBuilder {
             .collect();
 
         let object_store = if database.store_names().contains(&self.name) {
-            let transaction = request
+            request
                 .transaction()
-                .ok_or_else(|| Error::TransactionNotFound)?;
-
-            transaction.object_store(&self.name)
+                .ok_or(Error::TransactionNotFound)
+                .and_then(|transaction| transaction.object_store(&self.name))
         } else {
             let mut params = ObjectStoreParams::new();
 
@@ -80,7 +96,8 @@ impl ObjectStoreBuilder {
             }
 
             database.create_object_store(&self.name, params)
-        }?;
+        }
+        .map_err(|err| UpgradeStep::CreateObjectStore(self.name.clone()).failed(err))?;
 
         for index in self.indexes {
             index.apply(&object_store)?;
@@ -96,9 +113,28 @@ impl ObjectStoreBuilder {
         }
 
         for index_name in indexes_to_remove {
-            object_store.delete_index(&index_name)?;
+            object_store.delete_index(&index_name).map_err(|err| {
+                UpgradeStep::DeleteIndex {
+                    object_store: self.name.clone(),
+                    index: index_name.clone(),
+                }
+                .failed(err)
+            })?;
         }
 
         Ok(())
     }
 }
+
+impl From<ObjectStoreSchema> for ObjectStoreBuilder {
+    fn from(schema: ObjectStoreSchema) -> Self {
+        let builder = Self::new(&schema.name)
+            .auto_increment(schema.auto_increment)
+            .key_path(schema.key_path);
+
+        schema
+            .indexes
+            .into_iter()
+            .fold(builder, |builder, index| builder.add_index(index.into()))
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+
+/// An operation which [`DatabaseBuilder`](super::DatabaseBuilder) performs on the schema of a database when upgrading
+/// it, as returned by [`DatabaseBuilder::plan`](super::DatabaseBuilder::plan).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaOperation {
+    /// Creating a new object store
+    CreateObjectStore(String),
+    /// Deleting an object store along with all its records
+    DeleteObjectStore(String),
+    /// Renaming an object store
+    RenameObjectStore {
+        /// Old name of the object store
+        from: String,
+        /// New name of the object store
+        to: String,
+    },
+    /// Creating an index on an object store
+    CreateIndex {
+        /// Name of the object store
+        object_store: String,
+        /// Name of the index
+        index: String,
+    },
+    /// Deleting an index whose definition changed and creating it again
+    RecreateIndex {
+        /// Name of the object store
+        object_store: String,
+        /// Name of the index
+        index: String,
+    },
+    /// Deleting an index of an object store
+    DeleteIndex {
+        /// Name of the object store
+        object_store: String,
+        /// Name of the index
+        index: String,
+    },
+    /// Running the migration for given version
+    RunMigration(u32),
+}
+
+impl SchemaOperation {
+    /// Returns `true` if the operation deletes an object store or an index.
+    pub fn is_destructive(&self) -> bool {
+        matches!(
+            self,
+            SchemaOperation::DeleteObjectStore(_)
+                | SchemaOperation::RecreateIndex { .. }
+                | SchemaOperation::DeleteIndex { .. }
+        )
+    }
+}
+
+impl fmt::Display for SchemaOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaOperation::CreateObjectStore(name) => write!(f, "create object store `{name}`"),
+            SchemaOperation::DeleteObjectStore(name) => write!(f, "delete object store `{name}`"),
+            SchemaOperation::RenameObjectStore { from, to } => {
+                write!(f, "rename object store `{from}` to `{to}`")
+            }
+            SchemaOperation::CreateIndex {
+                object_store,
+                index,
+            } => write!(f, "create index `{index}` on object store `{object_store}`"),
+            SchemaOperation::RecreateIndex {
+                object_store,
+                index,
+            } => write!(
+                f,
+                "recreate index `{index}` on object store `{object_store}`"
+            ),
+            SchemaOperation::DeleteIndex {
+                object_store,
+                index,
+            } => write!(f, "delete index `{index}` of object store `{object_store}`"),
+            SchemaOperation::RunMigration(version) => {
+                write!(f, "run migration for version {version}")
+            }
+        }
+    }
+}
+
+/// The operations [`DatabaseBuilder`](super::DatabaseBuilder) would perform to upgrade a database, returned by
+/// [`DatabaseBuilder::plan`](super::DatabaseBuilder::plan).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaPlan {
+    /// Current version of the database, or `0` if it does not exist
+    pub old_version: u32,
+    /// Version of the database after the upgrade
+    pub new_version: u32,
+    /// Operations of the upgrade, in the order in which they are performed. This is empty if the database does not
+    /// need to be upgraded.
+    pub operations: Vec<SchemaOperation>,
+}
+
+impl SchemaPlan {
+    /// Returns `true` if any operation of the plan is destructive.
+    pub fn is_destructive(&self) -> bool {
+        self.operations.iter().any(SchemaOperation::is_destructive)
+    }
+
+    /// Returns the destructive operations of the plan.
+    pub fn destructive_operations(&self) -> impl Iterator<Item = &SchemaOperation> {
+        self.operations
+            .iter()
+            .filter(|operation| operation.is_destructive())
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+
+use crate::Error;
+
+/// A step of a database upgrade performed by [`DatabaseBuilder`](super::DatabaseBuilder).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpgradeStep {
+    /// Creating a new object store, or opening an existing one to update it
+    CreateObjectStore(String),
+    /// Deleting an object store
+    DeleteObjectStore(String),
+    /// Renaming an object store
+    RenameObjectStore {
+        /// Old name of the object store
+        from: String,
+        /// New name of the object store
+        to: String,
+    },
+    /// Creating an index on an object store
+    CreateIndex {
+        /// Name of the object store
+        object_store: String,
+        /// Name of the index
+        index: String,
+    },
+    /// Deleting an index of an object store
+    DeleteIndex {
+        /// Name of the object store
+        object_store: String,
+        /// Name of the index
+        index: String,
+    },
+    /// Running the migration for given version
+    Migration(u32),
+}
+
+impl UpgradeStep {
+    /// Wraps the given error into an [`Error::UpgradeFailed`] for this step.
+    pub(crate) fn failed(self, source: Error) -> Error {
+        Error::UpgradeFailed {
+            step: self,
+            source: Box::new(source),
+        }
+    }
+}
+
+impl fmt::Display for UpgradeStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpgradeStep::CreateObjectStore(name) => write!(f, "create object store `{name}`"),
+            UpgradeStep::DeleteObjectStore(name) => write!(f, "delete object store `{name}`"),
+            UpgradeStep::RenameObjectStore { from, to } => {
+                write!(f, "rename object store `{from}` to `{to}`")
+            }
+            UpgradeStep::CreateIndex {
+                object_store,
+                index,
+            } => write!(f, "create index `{index}` on object store `{object_store}`"),
+            UpgradeStep::DeleteIndex {
+                object_store,
+                index,
+            } => write!(f, "delete index `{index}` of object store `{object_store}`"),
+            UpgradeStep::Migration(version) => write!(f, "run migration for version {version}"),
+        }
+    }
+}
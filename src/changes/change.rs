@@ -0,0 +1,105 @@
+use js_sys::{Object, Reflect};
+use wasm_bindgen::JsValue;
+
+use crate::Key;
+
+/// A change made to an object store by a committed transaction.
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "changes")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// Name of the changed object store
+    pub store_name: String,
+    /// Kind of the change
+    pub kind: ChangeKind,
+    /// Key of the changed record. This is `None` for [`ChangeKind::Clear`] and for deletes of a key range, in which
+    /// case any record of the store may have changed.
+    pub key: Option<Key>,
+}
+
+/// Kind of a [`Change`].
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "changes")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// A record was added or updated using [`ObjectStore::put`](crate::ObjectStore::put) or
+    /// [`Cursor::update`](crate::Cursor::update)
+    Put,
+    /// A record was added using [`ObjectStore::add`](crate::ObjectStore::add)
+    Add,
+    /// Records were deleted using [`ObjectStore::delete`](crate::ObjectStore::delete) or
+    /// [`Cursor::delete`](crate::Cursor::delete)
+    Delete,
+    /// All the records were deleted using [`ObjectStore::clear`](crate::ObjectStore::clear)
+    Clear,
+}
+
+impl ChangeKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ChangeKind::Put => "put",
+            ChangeKind::Add => "add",
+            ChangeKind::Delete => "delete",
+            ChangeKind::Clear => "clear",
+        }
+    }
+
+    fn from_str(kind: &str) -> Option<Self> {
+        match kind {
+            "put" => Some(ChangeKind::Put),
+            "add" => Some(ChangeKind::Add),
+            "delete" => Some(ChangeKind::Delete),
+            "clear" => Some(ChangeKind::Clear),
+            _ => None,
+        }
+    }
+}
+
+impl Change {
+    /// Converts the change to a plain object which can be posted over a `BroadcastChannel`.
+    pub(crate) fn to_message(&self) -> JsValue {
+        let message = Object::new();
+
+        let _ = Reflect::set(
+            &message,
+            &JsValue::from_str("store"),
+            &JsValue::from_str(&self.store_name),
+        );
+        let _ = Reflect::set(
+            &message,
+            &JsValue::from_str("kind"),
+            &JsValue::from_str(self.kind.as_str()),
+        );
+        if let Some(key) = &self.key {
+            let _ = Reflect::set(&message, &JsValue::from_str("key"), &key.into());
+        }
+
+        message.into()
+    }
+
+    /// Reads a change from an object created by [`Change::to_message`]. Returns `None` for unrecognized messages.
+    pub(crate) fn from_message(message: &JsValue) -> Option<Self> {
+        if !message.is_object() {
+            return None;
+        }
+
+        let store_name = Reflect::get(message, &JsValue::from_str("store"))
+            .ok()?
+            .as_string()?;
+        let kind = Reflect::get(message, &JsValue::from_str("kind"))
+            .ok()?
+            .as_string()
+            .and_then(|kind| ChangeKind::from_str(&kind))?;
+        let key = Reflect::get(message, &JsValue::from_str("key")).ok()?;
+
+        let key = if key.is_undefined() {
+            None
+        } else {
+            Some(key.try_into().ok()?)
+        };
+
+        Some(Self {
+            store_name,
+            kind,
+            key,
+        })
+    }
+}
@@ -0,0 +1,184 @@
+use std::{
+    cell::RefCell,
+    pin::Pin,
+    rc::{Rc, Weak},
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use js_sys::Array;
+use tokio::sync::mpsc;
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{BroadcastChannel, MessageEvent};
+
+use crate::{Change, Error, Transaction};
+
+use super::registry;
+
+/// An opt-in feed of the changes made to a database, shared between all the tabs (and workers) of an origin.
+///
+/// Transactions are tracked using [`ChangeFeed::watch`]. Once a watched transaction commits, the changes made by its
+/// `put`, `add`, `delete` and `clear` requests (including [`Cursor::update`](crate::Cursor::update) and
+/// [`Cursor::delete`](crate::Cursor::delete)) are published to the local streams of the feed and, over a
+/// `BroadcastChannel`, to every other [`ChangeFeed`] of the same database. Nothing is published for aborted
+/// transactions.
+///
+/// ```rust
+/// use futures::StreamExt;
+/// use idb::{ChangeFeed, Database, Error, TransactionMode};
+/// use wasm_bindgen::JsValue;
+///
+/// async fn watch_employees(database: &Database) -> Result<(), Error> {
+///     let feed = ChangeFeed::new(&database.name())?;
+///     let mut changes = feed.store_changes("employees");
+///
+///     let transaction = database.transaction(&["employees"], TransactionMode::ReadWrite)?;
+///     feed.watch(&transaction);
+///
+///     let store = transaction.object_store("employees")?;
+///     store.put(&JsValue::from_str("John Doe"), Some(&JsValue::from(1)))?.await?;
+///     transaction.commit()?.await?;
+///
+///     while let Some(_change) = changes.next().await {
+///         // Refresh the UI
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "changes")))]
+#[derive(Debug, Clone)]
+pub struct ChangeFeed {
+    inner: Rc<ChangeFeedInner>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ChangeFeedInner {
+    channel: BroadcastChannel,
+    subscribers: RefCell<Vec<Subscriber>>,
+    message_callback: Closure<dyn FnMut(MessageEvent)>,
+}
+
+#[derive(Debug)]
+struct Subscriber {
+    /// Name of the object store whose changes are sent, or `None` for all the object stores
+    store_name: Option<String>,
+    sender: mpsc::UnboundedSender<Change>,
+}
+
+impl ChangeFeed {
+    /// Creates a new [`ChangeFeed`] for the database with the given name.
+    pub fn new(database_name: &str) -> Result<Self, Error> {
+        let channel = BroadcastChannel::new(&format!("idb-changes:{database_name}"))
+            .map_err(Error::BroadcastChannelCreateFailed)?;
+
+        let inner = Rc::new_cyclic(|weak: &Weak<ChangeFeedInner>| {
+            let weak = weak.clone();
+            let message_callback = Closure::new(move |event: MessageEvent| {
+                if let Some(inner) = weak.upgrade() {
+                    let changes = Array::from(&event.data());
+                    inner.dispatch(
+                        changes
+                            .iter()
+                            .filter_map(|change| Change::from_message(&change)),
+                    );
+                }
+            });
+
+            ChangeFeedInner {
+                channel,
+                subscribers: Default::default(),
+                message_callback,
+            }
+        });
+
+        inner
+            .channel
+            .set_onmessage(Some(inner.message_callback.as_ref().unchecked_ref()));
+
+        Ok(Self { inner })
+    }
+
+    /// Returns the name of the underlying `BroadcastChannel`.
+    pub fn channel_name(&self) -> String {
+        self.inner.channel.name()
+    }
+
+    /// Tracks the changes made by the transaction. They are published once the transaction commits.
+    pub fn watch(&self, transaction: &Transaction) {
+        registry::watch(transaction.as_inner(), &self.inner);
+    }
+
+    /// Returns a [`Stream`] of the changes made to all the object stores of the database, either locally or in other
+    /// tabs. The stream ends when the [`ChangeFeed`] (and all its clones) is dropped.
+    pub fn changes(&self) -> ChangeStream {
+        self.subscribe(None)
+    }
+
+    /// Returns a [`Stream`] of the changes made to the given object store, either locally or in other tabs. The stream
+    /// ends when the [`ChangeFeed`] (and all its clones) is dropped.
+    pub fn store_changes(&self, store_name: &str) -> ChangeStream {
+        self.subscribe(Some(store_name.to_owned()))
+    }
+
+    fn subscribe(&self, store_name: Option<String>) -> ChangeStream {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        self.inner
+            .subscribers
+            .borrow_mut()
+            .push(Subscriber { store_name, sender });
+
+        ChangeStream { receiver }
+    }
+}
+
+impl ChangeFeedInner {
+    /// Sends the changes of a committed transaction to the local streams and to the other tabs.
+    pub(crate) fn publish(&self, changes: Vec<Change>) {
+        if changes.is_empty() {
+            return;
+        }
+
+        let message = changes.iter().map(Change::to_message).collect::<Array>();
+        let _ = self.channel.post_message(&message);
+
+        self.dispatch(changes);
+    }
+
+    /// Sends the changes to the local streams, dropping the streams which are no longer polled.
+    fn dispatch(&self, changes: impl IntoIterator<Item = Change>) {
+        let mut subscribers = self.subscribers.borrow_mut();
+
+        for change in changes {
+            subscribers.retain(|subscriber| match &subscriber.store_name {
+                Some(store_name) if *store_name != change.store_name => {
+                    !subscriber.sender.is_closed()
+                }
+                _ => subscriber.sender.send(change.clone()).is_ok(),
+            });
+        }
+    }
+}
+
+impl Drop for ChangeFeedInner {
+    fn drop(&mut self) {
+        self.channel.set_onmessage(None);
+        self.channel.close();
+    }
+}
+
+/// A [`Stream`] of the changes published by a [`ChangeFeed`].
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "changes")))]
+#[derive(Debug)]
+pub struct ChangeStream {
+    receiver: mpsc::UnboundedReceiver<Change>,
+}
+
+impl Stream for ChangeStream {
+    type Item = Change;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().receiver.poll_recv(cx)
+    }
+}
@@ -0,0 +1,10 @@
+mod change;
+mod change_feed;
+mod registry;
+
+pub use self::{
+    change::{Change, ChangeKind},
+    change_feed::{ChangeFeed, ChangeStream},
+};
+
+pub(crate) use self::registry::{record, record_cursor};
@@ -0,0 +1,153 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+};
+
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{Event, IdbCursor, IdbIndex, IdbObjectStore, IdbRequest, IdbTransaction};
+
+use crate::{Change, ChangeKind, Key};
+
+use super::change_feed::ChangeFeedInner;
+
+thread_local! {
+    /// Transactions watched by a [`ChangeFeed`](crate::ChangeFeed) which have not finished yet.
+    static WATCHED: RefCell<Vec<Watched>> = const { RefCell::new(Vec::new()) };
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A transaction watched by a feed along with the changes made by its successful requests.
+struct Watched {
+    id: usize,
+    transaction: IdbTransaction,
+    feed: Weak<ChangeFeedInner>,
+    changes: Rc<RefCell<Vec<Change>>>,
+    /// Event listeners of the transaction and its requests, dropped once the transaction finishes
+    listeners: Vec<Closure<dyn FnMut(Event)>>,
+}
+
+/// Starts tracking the changes made by the transaction for the feed.
+pub(crate) fn watch(transaction: &IdbTransaction, feed: &Rc<ChangeFeedInner>) {
+    let already_watched = WATCHED.with_borrow(|watched| {
+        watched.iter().any(|watched| {
+            watched.transaction == *transaction && watched.feed.as_ptr() == Rc::as_ptr(feed)
+        })
+    });
+
+    if already_watched {
+        return;
+    }
+
+    let id = NEXT_ID.replace(NEXT_ID.get() + 1);
+
+    let complete_callback: Closure<dyn FnMut(Event)> = Closure::once(move |_: Event| {
+        if let Some(watched) = finish(id) {
+            if let Some(feed) = watched.feed.upgrade() {
+                feed.publish(watched.changes.take());
+            }
+        }
+    });
+    let abort_callback: Closure<dyn FnMut(Event)> = Closure::once(move |_: Event| {
+        finish(id);
+    });
+
+    let _ = transaction
+        .add_event_listener_with_callback("complete", complete_callback.as_ref().unchecked_ref());
+    let _ = transaction
+        .add_event_listener_with_callback("abort", abort_callback.as_ref().unchecked_ref());
+
+    WATCHED.with_borrow_mut(|watched| {
+        watched.push(Watched {
+            id,
+            transaction: transaction.clone(),
+            feed: Rc::downgrade(feed),
+            changes: Default::default(),
+            listeners: vec![complete_callback, abort_callback],
+        })
+    });
+}
+
+/// Records the change made by a write request on the object store if its transaction is watched. The change is only
+/// kept if the request succeeds. When `key` is `None` for puts and adds, the key is read from the result of the request.
+pub(crate) fn record(
+    request: &IdbRequest,
+    store: &IdbObjectStore,
+    kind: ChangeKind,
+    key: Option<Key>,
+) {
+    if WATCHED.with_borrow(Vec::is_empty) {
+        return;
+    }
+
+    let transaction = store.transaction();
+
+    WATCHED.with_borrow_mut(|watched| {
+        for watched in watched
+            .iter_mut()
+            .filter(|watched| watched.transaction == transaction)
+        {
+            let changes = watched.changes.clone();
+            let result_request = request.clone();
+            let store_name = store.name();
+            let key = key.clone();
+
+            let success_callback: Closure<dyn FnMut(Event)> = Closure::once(move |_: Event| {
+                let key = match (key, kind) {
+                    (None, ChangeKind::Put | ChangeKind::Add) => result_request
+                        .result()
+                        .ok()
+                        .and_then(|result| Key::try_from(result).ok()),
+                    (key, _) => key,
+                };
+
+                changes.borrow_mut().push(Change {
+                    store_name,
+                    kind,
+                    key,
+                });
+            });
+
+            let _ = request.add_event_listener_with_callback(
+                "success",
+                success_callback.as_ref().unchecked_ref(),
+            );
+            watched.listeners.push(success_callback);
+        }
+    });
+}
+
+/// Records the change made by a write request on the record pointed at by the cursor if its transaction is watched.
+pub(crate) fn record_cursor(request: &IdbRequest, cursor: &IdbCursor, kind: ChangeKind) {
+    if WATCHED.with_borrow(Vec::is_empty) {
+        return;
+    }
+
+    let source = cursor.source();
+    let store = match source.dyn_ref::<IdbIndex>() {
+        Some(index) => index.object_store(),
+        None => match source.dyn_into::<IdbObjectStore>() {
+            Ok(store) => store,
+            Err(_) => return,
+        },
+    };
+    let key = cursor
+        .primary_key()
+        .ok()
+        .and_then(|key| Key::try_from(key).ok());
+
+    record(request, &store, kind, key);
+}
+
+/// Stops watching a finished transaction and returns its entry.
+fn finish(id: usize) -> Option<Watched> {
+    let mut watched = WATCHED.with_borrow_mut(|watched| {
+        let position = watched.iter().position(|watched| watched.id == id)?;
+        Some(watched.remove(position))
+    })?;
+
+    // The listeners cannot be dropped while one of them is running, so they are dropped after the event is handled
+    let listeners = std::mem::take(&mut watched.listeners);
+    wasm_bindgen_futures::spawn_local(async move { drop(listeners) });
+
+    Some(watched)
+}
@@ -0,0 +1,70 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{IdbIndex, IdbObjectStore};
+
+use crate::{Error, Index, ObjectStore};
+
+/// The [`ObjectStore`] or [`Index`] a cursor was opened from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CursorSource {
+    /// The cursor was opened from an object store
+    ObjectStore(ObjectStore),
+    /// The cursor was opened from an index
+    Index(Index),
+}
+
+impl CursorSource {
+    /// Returns the name of the object store or index.
+    pub fn name(&self) -> String {
+        match self {
+            CursorSource::ObjectStore(object_store) => object_store.name(),
+            CursorSource::Index(index) => index.name(),
+        }
+    }
+
+    /// Returns the object store, or the object store the index belongs to.
+    pub fn object_store(&self) -> ObjectStore {
+        match self {
+            CursorSource::ObjectStore(object_store) => object_store.clone(),
+            CursorSource::Index(index) => index.object_store(),
+        }
+    }
+}
+
+impl From<ObjectStore> for CursorSource {
+    fn from(object_store: ObjectStore) -> Self {
+        CursorSource::ObjectStore(object_store)
+    }
+}
+
+impl From<Index> for CursorSource {
+    fn from(index: Index) -> Self {
+        CursorSource::Index(index)
+    }
+}
+
+impl TryFrom<JsValue> for CursorSource {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        if value.is_instance_of::<IdbObjectStore>() {
+            Ok(CursorSource::ObjectStore(
+                value.unchecked_into::<IdbObjectStore>().into(),
+            ))
+        } else if value.is_instance_of::<IdbIndex>() {
+            Ok(CursorSource::Index(
+                value.unchecked_into::<IdbIndex>().into(),
+            ))
+        } else {
+            Err(Error::UnexpectedJsType("IdbObjectStore or IdbIndex", value))
+        }
+    }
+}
+
+impl From<CursorSource> for JsValue {
+    fn from(source: CursorSource) -> Self {
+        match source {
+            CursorSource::ObjectStore(object_store) => object_store.into(),
+            CursorSource::Index(index) => index.into(),
+        }
+    }
+}
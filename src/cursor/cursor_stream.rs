@@ -0,0 +1,136 @@
+use std::{
+    future::{Future, IntoFuture},
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::{FusedStream, Stream};
+use wasm_bindgen::JsValue;
+
+use crate::{
+    request::futures::{OpenCursorStoreRequestFuture, OpenKeyCursorStoreRequestFuture},
+    Cursor, Error, Key, KeyCursor,
+};
+
+/// A [`Stream`] over the records of a [`Cursor`]. Yields the key, primary key and value of each record.
+///
+/// As soon as a record is yielded, the cursor is advanced to the next record so that the transaction stays alive
+/// across polls.
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+#[derive(Default)]
+pub struct CursorStream {
+    state: CursorStreamState<Cursor, OpenCursorStoreRequestFuture>,
+}
+
+/// A [`Stream`] over the records of a [`KeyCursor`]. Yields the key and primary key of each record.
+///
+/// As soon as a record is yielded, the cursor is advanced to the next record so that the transaction stays alive
+/// across polls.
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+#[derive(Default)]
+pub struct KeyCursorStream {
+    state: CursorStreamState<KeyCursor, OpenKeyCursorStoreRequestFuture>,
+}
+
+#[derive(Default)]
+enum CursorStreamState<C, F> {
+    /// The cursor points at a record which is not yet yielded.
+    Ready(C),
+    /// The cursor is advancing to the next record.
+    Advancing(F),
+    /// The cursor is finished.
+    #[default]
+    Done,
+}
+
+impl CursorStream {
+    pub(crate) fn new(cursor: Option<Cursor>) -> Self {
+        Self {
+            state: cursor.map_or(CursorStreamState::Done, CursorStreamState::Ready),
+        }
+    }
+}
+
+impl KeyCursorStream {
+    pub(crate) fn new(cursor: Option<KeyCursor>) -> Self {
+        Self {
+            state: cursor.map_or(CursorStreamState::Done, CursorStreamState::Ready),
+        }
+    }
+}
+
+impl Stream for CursorStream {
+    type Item = Result<(Key, Key, JsValue), Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        poll_next(&mut this.state, cx, |cursor| {
+            let record = (cursor.key()?, cursor.primary_key()?, cursor.value()?);
+            let next = cursor.next(None)?.into_future();
+
+            Ok((record, next))
+        })
+    }
+}
+
+impl FusedStream for CursorStream {
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, CursorStreamState::Done)
+    }
+}
+
+impl Stream for KeyCursorStream {
+    type Item = Result<(Key, Key), Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        poll_next(&mut this.state, cx, |cursor| {
+            let record = (cursor.key()?, cursor.primary_key()?);
+            let next = cursor.next(None)?.into_future();
+
+            Ok((record, next))
+        })
+    }
+}
+
+impl FusedStream for KeyCursorStream {
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, CursorStreamState::Done)
+    }
+}
+
+fn poll_next<C, F, T>(
+    state: &mut CursorStreamState<C, F>,
+    cx: &mut Context<'_>,
+    read: impl Fn(&C) -> Result<(T, F), Error>,
+) -> Poll<Option<Result<T, Error>>>
+where
+    F: Future<Output = Result<Option<C>, Error>> + Unpin,
+{
+    loop {
+        match mem::take(state) {
+            CursorStreamState::Ready(cursor) => {
+                return match read(&cursor) {
+                    Ok((record, next)) => {
+                        *state = CursorStreamState::Advancing(next);
+                        Poll::Ready(Some(Ok(record)))
+                    }
+                    Err(err) => Poll::Ready(Some(Err(err))),
+                };
+            }
+            CursorStreamState::Advancing(mut next) => match Pin::new(&mut next).poll(cx) {
+                Poll::Pending => {
+                    *state = CursorStreamState::Advancing(next);
+                    return Poll::Pending;
+                }
+                Poll::Ready(Ok(Some(cursor))) => *state = CursorStreamState::Ready(cursor),
+                Poll::Ready(Ok(None)) => return Poll::Ready(None),
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(err))),
+            },
+            CursorStreamState::Done => return Poll::Ready(None),
+        }
+    }
+}
@@ -1,12 +1,13 @@
-use js_sys::Object;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::IdbCursor;
 
 use crate::{
     request::{DeleteStoreRequest, OpenKeyCursorStoreRequest, UpdateStoreRequest},
-    CursorDirection, Error,
+    CursorDirection, CursorSource, Error, Key,
 };
 
+#[cfg(feature = "changes")]
+use crate::ChangeKind;
 #[cfg(feature = "futures")]
 use crate::ManagedKeyCursor;
 
@@ -18,9 +19,8 @@ pub struct KeyCursor {
 
 impl KeyCursor {
     /// Returns the [`ObjectStore`](crate::ObjectStore) or [`Index`](crate::Index) the cursor was opened from.
-    // TODO: make return type as enum: (IDBObjectStore or IDBIndex)
-    pub fn source(&self) -> Object {
-        self.inner.source()
+    pub fn source(&self) -> Result<CursorSource, Error> {
+        JsValue::from(self.inner.source()).try_into()
     }
 
     /// Returns the direction of the cursor.
@@ -29,15 +29,19 @@ impl KeyCursor {
     }
 
     /// Returns the key of the cursor. Returns an [`Error`] if the cursor is advancing or is finished.
-    pub fn key(&self) -> Result<JsValue, Error> {
-        self.inner.key().map_err(Error::CursorKeyNotFound)
+    pub fn key(&self) -> Result<Key, Error> {
+        self.inner
+            .key()
+            .map_err(Error::CursorKeyNotFound)?
+            .try_into()
     }
 
     /// Returns the effective key of the cursor. Returns an [`Error`] if the cursor is advancing or is finished.
-    pub fn primary_key(&self) -> Result<JsValue, Error> {
+    pub fn primary_key(&self) -> Result<Key, Error> {
         self.inner
             .primary_key()
-            .map_err(Error::CursorPrimaryKeyNotFound)
+            .map_err(Error::CursorPrimaryKeyNotFound)?
+            .try_into()
     }
 
     /// Returns the [`OpenKeyCursorStoreRequest`] that was used to obtain this cursor.
@@ -85,18 +89,22 @@ impl KeyCursor {
 
     /// Updated the record pointed at by the cursor with a new value.
     pub fn update(&self, value: &JsValue) -> Result<UpdateStoreRequest, Error> {
-        self.inner
-            .update(value)
-            .map(Into::into)
-            .map_err(Error::UpdateFailed)
+        let request = self.inner.update(value).map_err(Error::UpdateFailed)?;
+
+        #[cfg(feature = "changes")]
+        crate::changes::record_cursor(&request, &self.inner, ChangeKind::Put);
+
+        Ok(request.into())
     }
 
     /// Delete the record pointed at by the cursor with a new value.
     pub fn delete(&self) -> Result<DeleteStoreRequest, Error> {
-        self.inner
-            .delete()
-            .map(Into::into)
-            .map_err(Error::DeleteFailed)
+        let request = self.inner.delete().map_err(Error::DeleteFailed)?;
+
+        #[cfg(feature = "changes")]
+        crate::changes::record_cursor(&request, &self.inner, ChangeKind::Delete);
+
+        Ok(request.into())
     }
 
     /// Returns a managed version of this cursor.
@@ -1,7 +1,6 @@
-use js_sys::Object;
 use wasm_bindgen::JsValue;
 
-use crate::{CursorDirection, Error, KeyCursor};
+use crate::{CursorDirection, CursorSource, Error, Key, KeyCursor, KeyCursorStream};
 
 /// A key cursor that is managed by the library (for ease of use).
 #[cfg(feature = "futures")]
@@ -12,10 +11,13 @@ pub struct ManagedKeyCursor {
 }
 
 impl ManagedKeyCursor {
-    /// Returns the [`ObjectStore`](crate::ObjectStore) or [`Index`](crate::Index) the cursor was opened from.
-    // TODO: make return type as enum: (IDBObjectStore or IDBIndex)
-    pub fn source(&self) -> Option<Object> {
-        self.inner.as_ref().map(|cursor| cursor.source())
+    /// Returns the [`ObjectStore`](crate::ObjectStore) or [`Index`](crate::Index) the cursor was opened from, or
+    /// `None` if the cursor is finished.
+    pub fn source(&self) -> Result<Option<CursorSource>, Error> {
+        self.inner
+            .as_ref()
+            .map(|cursor| cursor.source())
+            .transpose()
     }
 
     /// Returns the direction of the cursor.
@@ -27,12 +29,12 @@ impl ManagedKeyCursor {
     }
 
     /// Returns the key of the cursor. Returns an [`Error`] if the cursor is advancing or is finished.
-    pub fn key(&self) -> Result<Option<JsValue>, Error> {
+    pub fn key(&self) -> Result<Option<Key>, Error> {
         self.inner.as_ref().map(|cursor| cursor.key()).transpose()
     }
 
     /// Returns the effective key of the cursor. Returns an [`Error`] if the cursor is advancing or is finished.
-    pub fn primary_key(&self) -> Result<Option<JsValue>, Error> {
+    pub fn primary_key(&self) -> Result<Option<Key>, Error> {
         self.inner
             .as_ref()
             .map(|cursor| cursor.primary_key())
@@ -97,6 +99,14 @@ impl ManagedKeyCursor {
             .delete()?
             .await
     }
+
+    /// Converts the cursor into a [`Stream`](futures_core::Stream) yielding the key and primary key of each remaining record.
+    ///
+    /// The cursor is advanced as soon as a record is yielded, so the transaction stays alive while the stream is
+    /// being polled.
+    pub fn into_stream(self) -> KeyCursorStream {
+        KeyCursorStream::new(self.inner)
+    }
 }
 
 impl From<KeyCursor> for ManagedKeyCursor {
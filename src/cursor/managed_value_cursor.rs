@@ -1,7 +1,6 @@
-use js_sys::Object;
 use wasm_bindgen::JsValue;
 
-use crate::{Cursor, CursorDirection, Error};
+use crate::{Cursor, CursorDirection, CursorSource, CursorStream, Error, Key};
 
 /// A cursor that is managed by the library (for ease of use).
 #[cfg(feature = "futures")]
@@ -12,10 +11,13 @@ pub struct ManagedCursor {
 }
 
 impl ManagedCursor {
-    /// Returns the [`ObjectStore`](crate::ObjectStore) or [`Index`](crate::Index) the cursor was opened from.
-    // TODO: make return type as enum: (IDBObjectStore or IDBIndex)
-    pub fn source(&self) -> Option<Object> {
-        self.inner.as_ref().map(|cursor| cursor.source())
+    /// Returns the [`ObjectStore`](crate::ObjectStore) or [`Index`](crate::Index) the cursor was opened from, or
+    /// `None` if the cursor is finished.
+    pub fn source(&self) -> Result<Option<CursorSource>, Error> {
+        self.inner
+            .as_ref()
+            .map(|cursor| cursor.source())
+            .transpose()
     }
 
     /// Returns the direction of the cursor.
@@ -27,12 +29,12 @@ impl ManagedCursor {
     }
 
     /// Returns the key of the cursor. Returns an [`Error`] if the cursor is advancing or is finished.
-    pub fn key(&self) -> Result<Option<JsValue>, Error> {
+    pub fn key(&self) -> Result<Option<Key>, Error> {
         self.inner.as_ref().map(|cursor| cursor.key()).transpose()
     }
 
     /// Returns the effective key of the cursor. Returns an [`Error`] if the cursor is advancing or is finished.
-    pub fn primary_key(&self) -> Result<Option<JsValue>, Error> {
+    pub fn primary_key(&self) -> Result<Option<Key>, Error> {
         self.inner
             .as_ref()
             .map(|cursor| cursor.primary_key())
@@ -102,6 +104,15 @@ impl ManagedCursor {
             .delete()?
             .await
     }
+
+    /// Converts the cursor into a [`Stream`](futures_core::Stream) yielding the key, primary key and value of each
+    /// remaining record.
+    ///
+    /// The cursor is advanced as soon as a record is yielded, so the transaction stays alive while the stream is
+    /// being polled.
+    pub fn into_stream(self) -> CursorStream {
+        CursorStream::new(self.inner)
+    }
 }
 
 impl From<Cursor> for ManagedCursor {
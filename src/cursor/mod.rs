@@ -1,4 +1,7 @@
 mod cursor_direction;
+mod cursor_source;
+#[cfg(feature = "futures")]
+mod cursor_stream;
 mod key_cursor;
 #[cfg(feature = "futures")]
 mod managed_key_cursor;
@@ -6,6 +9,13 @@ mod managed_key_cursor;
 mod managed_value_cursor;
 mod value_cursor;
 
-pub use self::{cursor_direction::CursorDirection, key_cursor::KeyCursor, value_cursor::Cursor};
+pub use self::{
+    cursor_direction::CursorDirection, cursor_source::CursorSource, key_cursor::KeyCursor,
+    value_cursor::Cursor,
+};
 #[cfg(feature = "futures")]
-pub use self::{managed_key_cursor::ManagedKeyCursor, managed_value_cursor::ManagedCursor};
+pub use self::{
+    cursor_stream::{CursorStream, KeyCursorStream},
+    managed_key_cursor::ManagedKeyCursor,
+    managed_value_cursor::ManagedCursor,
+};
@@ -1,12 +1,13 @@
-use js_sys::Object;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::IdbCursorWithValue;
 
+#[cfg(feature = "changes")]
+use crate::ChangeKind;
 #[cfg(feature = "futures")]
 use crate::ManagedCursor;
 use crate::{
     request::{DeleteStoreRequest, OpenCursorStoreRequest, UpdateStoreRequest},
-    CursorDirection, Error,
+    CursorDirection, CursorSource, Error, Key,
 };
 
 /// Represents a cursor for traversing or iterating over multiple records in a database.
@@ -17,9 +18,8 @@ pub struct Cursor {
 
 impl Cursor {
     /// Returns the [`ObjectStore`](crate::ObjectStore) or [`Index`](crate::Index) the cursor was opened from.
-    // TODO: make return type as enum: (IDBObjectStore or IDBIndex)
-    pub fn source(&self) -> Object {
-        self.inner.source()
+    pub fn source(&self) -> Result<CursorSource, Error> {
+        JsValue::from(self.inner.source()).try_into()
     }
 
     /// Returns the direction of the cursor.
@@ -28,15 +28,19 @@ impl Cursor {
     }
 
     /// Returns the key of the cursor. Returns an [`Error`] if the cursor is advancing or is finished.
-    pub fn key(&self) -> Result<JsValue, Error> {
-        self.inner.key().map_err(Error::CursorKeyNotFound)
+    pub fn key(&self) -> Result<Key, Error> {
+        self.inner
+            .key()
+            .map_err(Error::CursorKeyNotFound)?
+            .try_into()
     }
 
     /// Returns the effective key of the cursor. Returns an [`Error`] if the cursor is advancing or is finished.
-    pub fn primary_key(&self) -> Result<JsValue, Error> {
+    pub fn primary_key(&self) -> Result<Key, Error> {
         self.inner
             .primary_key()
-            .map_err(Error::CursorPrimaryKeyNotFound)
+            .map_err(Error::CursorPrimaryKeyNotFound)?
+            .try_into()
     }
 
     /// Returns the cursor's current value. Returns an [`Error`] if the cursor is advancing or is finished.
@@ -89,18 +93,22 @@ impl Cursor {
 
     /// Updated the record pointed at by the cursor with a new value.
     pub fn update(&self, value: &JsValue) -> Result<UpdateStoreRequest, Error> {
-        self.inner
-            .update(value)
-            .map(Into::into)
-            .map_err(Error::UpdateFailed)
+        let request = self.inner.update(value).map_err(Error::UpdateFailed)?;
+
+        #[cfg(feature = "changes")]
+        crate::changes::record_cursor(&request, &self.inner, ChangeKind::Put);
+
+        Ok(request.into())
     }
 
     /// Delete the record pointed at by the cursor with a new value.
     pub fn delete(&self) -> Result<DeleteStoreRequest, Error> {
-        self.inner
-            .delete()
-            .map(Into::into)
-            .map_err(Error::DeleteFailed)
+        let request = self.inner.delete().map_err(Error::DeleteFailed)?;
+
+        #[cfg(feature = "changes")]
+        crate::changes::record_cursor(&request, &self.inner, ChangeKind::Delete);
+
+        Ok(request.into())
     }
 
     /// Returns a managed cursor.
@@ -1,13 +1,18 @@
-use js_sys::Array;
+#[cfg(feature = "futures")]
+use std::future::{Future, IntoFuture};
+
+use js_sys::{Array, Function, Reflect};
 use num_traits::ToPrimitive;
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
-use web_sys::{Event, EventTarget, IdbDatabase};
+use web_sys::{Event, EventTarget, IdbDatabase, IdbTransaction};
 
 #[cfg(feature = "builder")]
 use crate::builder::DatabaseBuilder;
+#[cfg(feature = "futures")]
+use crate::{event::DatabaseEventStream, TransactionResult};
 use crate::{
-    utils::dom_string_list_to_vec, Error, ObjectStore, ObjectStoreParams, Transaction,
-    TransactionMode,
+    utils::dom_string_list_to_vec, DatabaseSchema, Error, EventListener, ObjectStore,
+    ObjectStoreParams, Transaction, TransactionMode, TransactionOptions,
 };
 
 /// [`Database`] provides a connection to a database; you can use an [`Database`] object to open a transaction on your
@@ -20,6 +25,9 @@ pub struct Database {
     close_callback: Option<Closure<dyn FnMut(Event)>>,
     error_callback: Option<Closure<dyn FnMut(Event)>>,
     version_change_callback: Option<Closure<dyn FnMut(Event)>>,
+    /// Listener added by [`DatabaseBuilder::build`] to close the connection on `versionchange` event
+    #[cfg(feature = "builder")]
+    version_change_listener: Option<EventListener>,
 }
 
 impl Database {
@@ -48,6 +56,31 @@ impl Database {
         dom_string_list_to_vec(&self.inner.object_store_names())
     }
 
+    /// Returns a snapshot of the schema of the database: its object stores and their indexes.
+    ///
+    /// The object stores are read in a new read-only transaction, so this cannot be called while an upgrade is in
+    /// progress. Within an upgrade, use [`ObjectStore::schema`] on the object stores of the upgrade transaction instead.
+    pub fn schema(&self) -> Result<DatabaseSchema, Error> {
+        let store_names = self.store_names();
+
+        let object_stores = if store_names.is_empty() {
+            Vec::new()
+        } else {
+            let transaction = self.transaction(&store_names, TransactionMode::ReadOnly)?;
+
+            store_names
+                .iter()
+                .map(|name| transaction.object_store(name)?.schema())
+                .collect::<Result<_, _>>()?
+        };
+
+        Ok(DatabaseSchema {
+            name: self.name(),
+            version: self.version()?,
+            object_stores,
+        })
+    }
+
     /// Returns a new transaction with the given scope (which can be a single object store name or an array of names),
     /// mode ([`TransactionMode::ReadOnly`] or [`TransactionMode::ReadWrite`]).
     pub fn transaction<T>(
@@ -58,17 +91,89 @@ impl Database {
     where
         T: AsRef<str>,
     {
-        let store_names: Array = store_names
-            .iter()
-            .map(|s| JsValue::from(s.as_ref()))
-            .collect();
-
         self.inner
-            .transaction_with_str_sequence_and_mode(&store_names, mode.into())
+            .transaction_with_str_sequence_and_mode(&store_names_to_array(store_names), mode.into())
             .map(Into::into)
             .map_err(Error::TransactionOpenFailed)
     }
 
+    /// Returns a new transaction with the given scope (which can be a single object store name or an array of names),
+    /// mode ([`TransactionMode::ReadOnly`] or [`TransactionMode::ReadWrite`]) and options.
+    pub fn transaction_with_options<T>(
+        &self,
+        store_names: &[T],
+        mode: TransactionMode,
+        options: TransactionOptions,
+    ) -> Result<Transaction, Error>
+    where
+        T: AsRef<str>,
+    {
+        // `IDBDatabase.transaction()` with options is not yet stable in `web-sys`
+        let transaction: Function = Reflect::get(&self.inner, &JsValue::from_str("transaction"))
+            .map_err(Error::TransactionOpenFailed)?
+            .unchecked_into();
+
+        transaction
+            .call3(
+                &self.inner,
+                &store_names_to_array(store_names),
+                &mode.into(),
+                &options.into(),
+            )
+            .and_then(|transaction| transaction.dyn_into::<IdbTransaction>())
+            .map(Into::into)
+            .map_err(Error::TransactionOpenFailed)
+    }
+
+    /// Runs the given closure in a new transaction with the given scope and mode.
+    ///
+    /// The transaction is committed if the closure returns `Ok` and aborted if it returns `Err`. On success, returns
+    /// the value returned by the closure along with the [`TransactionResult`]. If the transaction is aborted because of
+    /// an error (for example, a failed request), the error is returned instead.
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    pub async fn with_transaction<T, F, Fut, R, E>(
+        &self,
+        store_names: &[T],
+        mode: TransactionMode,
+        f: F,
+    ) -> Result<(R, TransactionResult), E>
+    where
+        T: AsRef<str>,
+        F: FnOnce(Transaction) -> Fut,
+        Fut: Future<Output = Result<R, E>>,
+        E: From<Error>,
+    {
+        let inner: IdbTransaction = self.transaction(store_names, mode)?.into();
+
+        // Listen for transaction events before running the closure so that no event is missed
+        let transaction_future = Transaction::from(inner.clone()).into_future();
+
+        match f(Transaction::from(inner.clone())).await {
+            Ok(value) => {
+                // The transaction may already have finished, in which case there is nothing to commit
+                let _ = Transaction::from(inner.clone()).commit();
+
+                let result = transaction_future.await?;
+
+                if result.is_aborted() {
+                    if let Some(error) = inner.error() {
+                        return Err(Error::DomException(error).into());
+                    }
+                }
+
+                Ok((value, result))
+            }
+            Err(err) => {
+                // The transaction may already have finished, in which case there is nothing to abort
+                let _ = Transaction::from(inner).abort();
+                let _ = transaction_future.await;
+
+                Err(err)
+            }
+        }
+    }
+
     /// Closes the connection once all running transactions have finished.
     pub fn close(&self) {
         self.inner.close()
@@ -94,7 +199,8 @@ impl Database {
             .map_err(Error::ObjectStoreDeleteFailed)
     }
 
-    /// Adds an event handler for `abort` event.
+    /// Adds an event handler for `abort` event. This replaces the handler previously added using this method; use
+    /// [`Database::add_abort_listener`] to add multiple handlers.
     pub fn on_abort<F>(&mut self, callback: F)
     where
         F: FnOnce(Event) + 'static,
@@ -105,7 +211,8 @@ impl Database {
         self.abort_callback = Some(closure);
     }
 
-    /// Adds an event handler for `close` event.
+    /// Adds an event handler for `close` event. This replaces the handler previously added using this method; use
+    /// [`Database::add_close_listener`] to add multiple handlers.
     pub fn on_close<F>(&mut self, callback: F)
     where
         F: FnOnce(Event) + 'static,
@@ -116,7 +223,8 @@ impl Database {
         self.close_callback = Some(closure);
     }
 
-    /// Adds an event handler for `error` event.
+    /// Adds an event handler for `error` event. This replaces the handler previously added using this method; use
+    /// [`Database::add_error_listener`] to add multiple handlers.
     pub fn on_error<F>(&mut self, callback: F)
     where
         F: FnOnce(Event) + 'static,
@@ -127,7 +235,8 @@ impl Database {
         self.error_callback = Some(closure);
     }
 
-    /// Adds an event handler for `versionchange` event.
+    /// Adds an event handler for `versionchange` event. This replaces the handler previously added using this method; use
+    /// [`Database::add_version_change_listener`] to add multiple handlers.
     pub fn on_version_change<F>(&mut self, callback: F)
     where
         F: FnOnce(Event) + 'static,
@@ -138,6 +247,56 @@ impl Database {
         self.version_change_callback = Some(closure);
     }
 
+    /// Adds a listener for `abort` event, fired when a transaction of the connection is aborted. The listener is
+    /// removed when the returned [`EventListener`] is dropped.
+    pub fn add_abort_listener<F>(&self, callback: F) -> Result<EventListener, Error>
+    where
+        F: FnMut(Event) + 'static,
+    {
+        EventListener::new(&self.inner, "abort", callback)
+    }
+
+    /// Adds a listener for `close` event, fired when the connection is closed unexpectedly. The listener is removed
+    /// when the returned [`EventListener`] is dropped.
+    pub fn add_close_listener<F>(&self, callback: F) -> Result<EventListener, Error>
+    where
+        F: FnMut(Event) + 'static,
+    {
+        EventListener::new(&self.inner, "close", callback)
+    }
+
+    /// Adds a listener for `error` event, fired when a request fails within a transaction of the connection. The
+    /// listener is removed when the returned [`EventListener`] is dropped.
+    pub fn add_error_listener<F>(&self, callback: F) -> Result<EventListener, Error>
+    where
+        F: FnMut(Event) + 'static,
+    {
+        EventListener::new(&self.inner, "error", callback)
+    }
+
+    /// Adds a listener for `versionchange` event, fired every time another connection requests to upgrade or delete
+    /// the database. The listener is removed when the returned [`EventListener`] is dropped.
+    pub fn add_version_change_listener<F>(&self, callback: F) -> Result<EventListener, Error>
+    where
+        F: FnMut(Event) + 'static,
+    {
+        EventListener::new(&self.inner, "versionchange", callback)
+    }
+
+    /// Keeps the `versionchange` listener added by [`DatabaseBuilder::build`] for as long as the connection.
+    #[cfg(feature = "builder")]
+    pub(crate) fn set_version_change_listener(&mut self, listener: EventListener) {
+        self.version_change_listener = Some(listener);
+    }
+
+    /// Returns a [`Stream`](futures_core::Stream) of the events fired on the connection. Events are only received
+    /// while the stream is alive.
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    pub fn events(&self) -> Result<DatabaseEventStream, Error> {
+        DatabaseEventStream::new(&self.inner)
+    }
+
     /// Release memory management of the callbacks to JS GC.
     ///
     /// > Note: This may leak memory. Read more about it
@@ -186,6 +345,8 @@ impl From<IdbDatabase> for Database {
             close_callback: None,
             error_callback: None,
             version_change_callback: None,
+            #[cfg(feature = "builder")]
+            version_change_listener: None,
         }
     }
 }
@@ -212,3 +373,13 @@ impl From<Database> for JsValue {
         value.inner.into()
     }
 }
+
+fn store_names_to_array<T>(store_names: &[T]) -> Array
+where
+    T: AsRef<str>,
+{
+    store_names
+        .iter()
+        .map(|s| JsValue::from(s.as_ref()))
+        .collect()
+}
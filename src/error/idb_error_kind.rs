@@ -0,0 +1,93 @@
+use std::fmt;
+
+/// Kind of an IndexedDB error, as given by the `name` of the `DOMException` raised by the browser.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IdbErrorKind {
+    /// `AbortError`: the request was aborted, e.g., because its transaction was aborted
+    Abort,
+    /// `ConstraintError`: a mutation operation in a transaction failed because a constraint was not satisfied, e.g.,
+    /// an object store or index with the same name already exists, or a unique index is violated
+    Constraint,
+    /// `DataCloneError`: the data being stored could not be cloned by the structured clone algorithm
+    DataClone,
+    /// `DataError`: the data provided to an operation does not meet the requirements, e.g., an invalid key
+    Data,
+    /// `InvalidAccessError`: an invalid operation was performed on an object
+    InvalidAccess,
+    /// `InvalidStateError`: an operation was called on an object which is not in a valid state for it, e.g., a deleted
+    /// object store or a finished cursor
+    InvalidState,
+    /// `NotFoundError`: the object store or index being accessed was not found
+    NotFound,
+    /// `NotReadableError`: the underlying storage could not be read
+    NotReadable,
+    /// `QuotaExceededError`: the operation failed because there was not enough remaining storage space
+    QuotaExceeded,
+    /// `ReadOnlyError`: a mutation operation was attempted in a read-only transaction
+    ReadOnly,
+    /// `SyntaxError`: an invalid key path or name was provided
+    Syntax,
+    /// `TimeoutError`: a lock for the transaction could not be obtained in a reasonable time
+    Timeout,
+    /// `TransactionInactiveError`: a request was placed against a transaction which is not active, e.g., because it
+    /// has already finished
+    TransactionInactive,
+    /// `UnknownError`: the operation failed for reasons unrelated to the database itself, e.g., an I/O error
+    Unknown,
+    /// `VersionError`: an attempt was made to open a database with a version lower than its current version
+    Version,
+    /// Any other exception name
+    Other(String),
+}
+
+impl IdbErrorKind {
+    /// Returns the [`IdbErrorKind`] for the given `DOMException` name.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "AbortError" => IdbErrorKind::Abort,
+            "ConstraintError" => IdbErrorKind::Constraint,
+            "DataCloneError" => IdbErrorKind::DataClone,
+            "DataError" => IdbErrorKind::Data,
+            "InvalidAccessError" => IdbErrorKind::InvalidAccess,
+            "InvalidStateError" => IdbErrorKind::InvalidState,
+            "NotFoundError" => IdbErrorKind::NotFound,
+            "NotReadableError" => IdbErrorKind::NotReadable,
+            "QuotaExceededError" => IdbErrorKind::QuotaExceeded,
+            "ReadOnlyError" => IdbErrorKind::ReadOnly,
+            "SyntaxError" => IdbErrorKind::Syntax,
+            "TimeoutError" => IdbErrorKind::Timeout,
+            "TransactionInactiveError" => IdbErrorKind::TransactionInactive,
+            "UnknownError" => IdbErrorKind::Unknown,
+            "VersionError" => IdbErrorKind::Version,
+            _ => IdbErrorKind::Other(name.to_owned()),
+        }
+    }
+
+    /// Returns the `DOMException` name of the error kind.
+    pub fn name(&self) -> &str {
+        match self {
+            IdbErrorKind::Abort => "AbortError",
+            IdbErrorKind::Constraint => "ConstraintError",
+            IdbErrorKind::DataClone => "DataCloneError",
+            IdbErrorKind::Data => "DataError",
+            IdbErrorKind::InvalidAccess => "InvalidAccessError",
+            IdbErrorKind::InvalidState => "InvalidStateError",
+            IdbErrorKind::NotFound => "NotFoundError",
+            IdbErrorKind::NotReadable => "NotReadableError",
+            IdbErrorKind::QuotaExceeded => "QuotaExceededError",
+            IdbErrorKind::ReadOnly => "ReadOnlyError",
+            IdbErrorKind::Syntax => "SyntaxError",
+            IdbErrorKind::Timeout => "TimeoutError",
+            IdbErrorKind::TransactionInactive => "TransactionInactiveError",
+            IdbErrorKind::Unknown => "UnknownError",
+            IdbErrorKind::Version => "VersionError",
+            IdbErrorKind::Other(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for IdbErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
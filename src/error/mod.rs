@@ -0,0 +1,438 @@
+mod idb_error_kind;
+
+pub use self::idb_error_kind::IdbErrorKind;
+
+use js_sys::Object;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::DomException;
+
+/// Error type for [`idb-sys`](crate) crate.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// Failed to add a value
+    #[error("failed to add a value: {}", js_object_display(.0))]
+    AddFailed(JsValue),
+
+    /// Failed to clear object store
+    #[error("failed to clear object store: {}", js_object_display(.0))]
+    ClearFailed(JsValue),
+
+    /// Failed to get count of records
+    #[error("failed to get count of records: {}", js_object_display(.0))]
+    CountFailed(JsValue),
+
+    /// Failed to advance cursor
+    #[error("failed to advance cursor: {}", js_object_display(.0))]
+    CursorAdvanceFailed(JsValue),
+
+    /// Failed to continue cursor
+    #[error("failed to continue cursor: {}", js_object_display(.0))]
+    CursorContinueFailed(JsValue),
+
+    /// Cursor is finished.
+    #[error("cursor is finished")]
+    CursorFinished,
+
+    /// Failed to get cursor key
+    #[error("failed to get cursor key: {}", js_object_display(.0))]
+    CursorKeyNotFound(JsValue),
+
+    /// Failed to get cursor primary key
+    #[error("failed to get cursor primary key: {}", js_object_display(.0))]
+    CursorPrimaryKeyNotFound(JsValue),
+
+    /// Failed to get cursor value
+    #[error("failed to get cursor value: {}", js_object_display(.0))]
+    CursorValueNotFound(JsValue),
+
+    /// Failed to list databases
+    #[error("failed to list databases: {}", js_object_display(.0))]
+    DatabasesListFailed(JsValue),
+
+    /// Failed to delete a value
+    #[error("failed to delete a value: {}", js_object_display(.0))]
+    DeleteFailed(JsValue),
+
+    /// DOM exception
+    #[error("DOM exception: {}", js_object_display(.0))]
+    DomException(web_sys::DomException),
+
+    /// DOM exception not found
+    #[error("DOM exception not found")]
+    DomExceptionNotFound,
+
+    /// Failed to add an event listener
+    #[error("failed to add event listener: {}", js_object_display(.0))]
+    EventListenerAddFailed(JsValue),
+
+    /// Failed to get event target
+    #[error("failed to get event target")]
+    EventTargetNotFound,
+
+    /// Failed to get all values
+    #[error("failed to get all values: {}", js_object_display(.0))]
+    GetAllFailed(JsValue),
+
+    /// Failed to get all keys
+    #[error("failed to get all keys: {}", js_object_display(.0))]
+    GetAllKeysFailed(JsValue),
+
+    /// Failed to get a value
+    #[error("failed to get a value: {}", js_object_display(.0))]
+    GetFailed(JsValue),
+
+    /// Failed to get a key
+    #[error("failed to get a key: {}", js_object_display(.0))]
+    GetKeyFailed(JsValue),
+
+    /// Failed to create new index
+    #[error("failed to create new index: {}", js_object_display(.0))]
+    IndexCreateFailed(JsValue),
+
+    /// Failed to delete index
+    #[error("failed to delete index: {}", js_object_display(.0))]
+    IndexDeleteFailed(JsValue),
+
+    /// Failed to delete indexed db
+    #[error("failed to delete indexed db: {}", js_object_display(.0))]
+    IndexedDbDeleteFailed(JsValue),
+
+    /// Indexed db not found
+    #[error("indexed db not found")]
+    IndexedDbNotFound(JsValue),
+
+    /// Failed to open indexed db
+    #[error("failed to open indexed db: {}", js_object_display(.0))]
+    IndexedDbOpenFailed(JsValue),
+
+    /// Failed to get index
+    #[error("failed to get index: {}", js_object_display(.0))]
+    IndexNotFound(JsValue),
+
+    /// Invalid continuation token
+    #[error("invalid continuation token")]
+    InvalidContinuation,
+
+    /// Invalid cursor direction
+    #[error("invalid cursor direction")]
+    InvalidCursorDirection,
+
+    /// Invalid key
+    #[error("invalid key: {}", js_object_display(.0))]
+    InvalidKey(JsValue),
+
+    /// Invalid key path of an object store
+    #[error("invalid key path of an object store")]
+    InvalidKeyPath,
+
+    /// Invalid key range
+    #[error("invalid key range: {0}")]
+    InvalidKeyRange(&'static str),
+
+    /// Invalid request ready state
+    #[error("invalid request ready state")]
+    InvalidReqeustReadyState,
+
+    /// Invalid storage type
+    #[error("invalid storage type")]
+    InvalidStorageType,
+
+    /// Invalid transaction durability
+    #[error("invalid transaction durability")]
+    InvalidTransactionDurability,
+
+    /// Invalid transaction mode
+    #[error("invalid transaction mode")]
+    InvalidTransactionMode,
+
+    /// Failed to compare keys
+    #[error("failed to compare keys: {}", js_object_display(.0))]
+    KeyCompareFailed(JsValue),
+
+    /// Failed to get key path of an object store
+    #[error("failed to get key path of an object store: {}", js_object_display(.0))]
+    KeyPathNotFound(JsValue),
+
+    /// Failed to get key range bound
+    #[error("failed to get key range bound: {}", js_object_display(.0))]
+    KeyRangeBoundNotFound(JsValue),
+
+    /// Failed to create key range
+    #[error("failed to create key range: {}", js_object_display(.0))]
+    KeyRangeCreateFailed(JsValue),
+
+    /// Failed to check if a value is included in key range
+    #[error("failed to check if a value is included in key range: {}", js_object_display(.0))]
+    KeyRangeIncludesFailed(JsValue),
+
+    /// Number conversion error
+    #[error("number conversion error")]
+    NumberConversionError,
+
+    /// Failed to create new object store
+    #[error("failed to create new object store: {}", js_object_display(.0))]
+    ObjectStoreCreateFailed(JsValue),
+
+    /// Failed to delete object store
+    #[error("failed to delete object store: {}", js_object_display(.0))]
+    ObjectStoreDeleteFailed(JsValue),
+
+    /// Failed to get object store
+    #[error("failed to get object store: {}", js_object_display(.0))]
+    ObjectStoreNotFound(JsValue),
+
+    /// Failed to rename object store
+    #[error("failed to rename object store: {}", js_object_display(.0))]
+    ObjectStoreRenameFailed(JsValue),
+
+    /// Failed to open cursor
+    #[error("failed to open cursor: {}", js_object_display(.0))]
+    OpenCursorFailed(JsValue),
+
+    /// Failed to open key cursor
+    #[error("failed to open key cursor: {}", js_object_display(.0))]
+    OpenKeyCursorFailed(JsValue),
+
+    /// Failed to get request error
+    #[error("failed to get request error: {}", js_object_display(.0))]
+    RequestErrorNotFound(JsValue),
+
+    /// Failed to get request result
+    #[error("failed to get request source: {}", js_object_display(.0))]
+    RequestResultNotFound(JsValue),
+
+    /// Failed to get request result
+    #[error("failed to get request source")]
+    RequestSourceNotFound,
+
+    /// Failed to schedule a timeout
+    #[error("failed to schedule timeout: {}", js_object_display(.0))]
+    SetTimeoutFailed(JsValue),
+
+    /// Failed to abort transaction
+    #[error("failed to abort transaction: {}", js_object_display(.0))]
+    TransactionAbortError(JsValue),
+
+    /// Failed to commit transaction
+    #[error("failed to commit transaction: {}", js_object_display(.0))]
+    TransactionCommitError(JsValue),
+
+    /// Failed to get transaction durability
+    #[error("failed to get transaction durability: {}", js_object_display(.0))]
+    TransactionDurabilityNotFound(JsValue),
+
+    /// Failed to get transaction mode
+    #[error("failed to get transaction mode: {}", js_object_display(.0))]
+    TransactionModeNotFound(JsValue),
+
+    /// Failed to open new transaction
+    #[error("failed to open new transaction: {}", js_object_display(.0))]
+    TransactionOpenFailed(JsValue),
+
+    /// Unexpected JS type
+    #[error("unexpected JS type. expected: {}, found: {}", .0, js_object_display(.1))]
+    UnexpectedJsType(&'static str, JsValue),
+
+    /// Failed to update a value
+    #[error("failed to update a value: {}", js_object_display(.0))]
+    UpdateFailed(JsValue),
+
+    /// Failed to receive object on oneshot channel
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    #[error("failed to receive object on oneshot channel")]
+    OneshotChannelReceiveError,
+
+    /// Request did not complete before its timeout and its transaction was aborted
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    #[error("request timed out")]
+    TimedOut,
+
+    /// Request was cancelled using an `AbortSignal` and its transaction was aborted
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    #[error("request was cancelled")]
+    Cancelled,
+
+    /// No transaction associated with database request
+    #[cfg(feature = "builder")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "builder")))]
+    #[error("no transaction associated with database request")]
+    TransactionNotFound,
+
+    /// Failed to upgrade database
+    #[cfg(feature = "builder")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "builder")))]
+    #[error("failed to upgrade database ({step}): {source}")]
+    UpgradeFailed {
+        /// Step of the upgrade which failed
+        step: crate::builder::UpgradeStep,
+        /// Error returned by the failed step
+        source: Box<Error>,
+    },
+
+    /// The upgrade of the database is blocked by other open connections which did not close in time
+    #[cfg(feature = "builder")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "builder")))]
+    #[error("upgrade of database from version {old} to version {new} is blocked by other open connections")]
+    UpgradeBlocked {
+        /// Current version of the database
+        old: u32,
+        /// Requested version of the database
+        new: u32,
+    },
+
+    /// The upgrade of the database was refused because it deletes object stores or indexes
+    #[cfg(feature = "builder")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "builder")))]
+    #[error("refused destructive upgrade: {}", list_display(.0))]
+    DestructiveUpgrade(Vec<crate::builder::SchemaOperation>),
+
+    /// Failed to serialize a value
+    #[cfg(feature = "serde")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+    #[error("failed to serialize a value: {}", js_object_display(.0))]
+    SerializeFailed(JsValue),
+
+    /// Failed to deserialize a value
+    #[cfg(feature = "serde")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+    #[error("failed to deserialize a value: {}", js_object_display(.0))]
+    DeserializeFailed(JsValue),
+
+    /// Error raised by the in-memory backend
+    #[cfg(feature = "memory")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "memory")))]
+    #[error("{0}: {1}")]
+    InMemory(IdbErrorKind, String),
+
+    /// Failed to create a broadcast channel
+    #[cfg(feature = "changes")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "changes")))]
+    #[error("failed to create a broadcast channel: {}", js_object_display(.0))]
+    BroadcastChannelCreateFailed(JsValue),
+
+    /// Backup is invalid
+    #[cfg(feature = "backup")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "backup")))]
+    #[error("invalid backup: {0}")]
+    InvalidBackup(String),
+
+    /// Value cannot be written to a backup
+    #[cfg(feature = "backup")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "backup")))]
+    #[error("value of type `{0}` cannot be backed up")]
+    UnsupportedBackupValue(String),
+}
+
+impl Error {
+    /// Returns the [`IdbErrorKind`] of the error if it was caused by a `DOMException` raised by the browser.
+    pub fn kind(&self) -> Option<IdbErrorKind> {
+        match self {
+            Error::DomException(exception) => Some(IdbErrorKind::from_name(&exception.name())),
+            #[cfg(feature = "builder")]
+            Error::UpgradeFailed { source, .. } => source.kind(),
+            #[cfg(feature = "memory")]
+            Error::InMemory(kind, _) => Some(kind.clone()),
+            _ => self
+                .js_payload()
+                .and_then(|value| value.dyn_ref::<DomException>())
+                .map(|exception| IdbErrorKind::from_name(&exception.name())),
+        }
+    }
+
+    /// Returns `true` if the error is a `ConstraintError`, e.g., a unique index or primary key is violated.
+    pub fn is_constraint_violation(&self) -> bool {
+        self.kind() == Some(IdbErrorKind::Constraint)
+    }
+
+    /// Returns `true` if the error is a `QuotaExceededError`.
+    pub fn is_quota_exceeded(&self) -> bool {
+        self.kind() == Some(IdbErrorKind::QuotaExceeded)
+    }
+
+    /// Returns `true` if the error is a `TransactionInactiveError`.
+    pub fn is_transaction_inactive(&self) -> bool {
+        self.kind() == Some(IdbErrorKind::TransactionInactive)
+    }
+
+    /// Returns `true` if the error is a `VersionError`.
+    pub fn is_version_error(&self) -> bool {
+        self.kind() == Some(IdbErrorKind::Version)
+    }
+
+    /// Returns `true` if the error is an `AbortError`.
+    pub fn is_aborted(&self) -> bool {
+        self.kind() == Some(IdbErrorKind::Abort)
+    }
+
+    /// Returns the JS value thrown by the browser for the variants which carry one.
+    fn js_payload(&self) -> Option<&JsValue> {
+        match self {
+            Error::AddFailed(value)
+            | Error::ClearFailed(value)
+            | Error::CountFailed(value)
+            | Error::CursorAdvanceFailed(value)
+            | Error::CursorContinueFailed(value)
+            | Error::CursorKeyNotFound(value)
+            | Error::CursorPrimaryKeyNotFound(value)
+            | Error::CursorValueNotFound(value)
+            | Error::DatabasesListFailed(value)
+            | Error::DeleteFailed(value)
+            | Error::EventListenerAddFailed(value)
+            | Error::GetAllFailed(value)
+            | Error::GetAllKeysFailed(value)
+            | Error::GetFailed(value)
+            | Error::GetKeyFailed(value)
+            | Error::IndexCreateFailed(value)
+            | Error::IndexDeleteFailed(value)
+            | Error::IndexedDbDeleteFailed(value)
+            | Error::IndexedDbNotFound(value)
+            | Error::IndexedDbOpenFailed(value)
+            | Error::IndexNotFound(value)
+            | Error::KeyCompareFailed(value)
+            | Error::KeyPathNotFound(value)
+            | Error::KeyRangeBoundNotFound(value)
+            | Error::KeyRangeCreateFailed(value)
+            | Error::KeyRangeIncludesFailed(value)
+            | Error::ObjectStoreCreateFailed(value)
+            | Error::ObjectStoreDeleteFailed(value)
+            | Error::ObjectStoreNotFound(value)
+            | Error::ObjectStoreRenameFailed(value)
+            | Error::OpenCursorFailed(value)
+            | Error::OpenKeyCursorFailed(value)
+            | Error::RequestErrorNotFound(value)
+            | Error::RequestResultNotFound(value)
+            | Error::SetTimeoutFailed(value)
+            | Error::TransactionAbortError(value)
+            | Error::TransactionCommitError(value)
+            | Error::TransactionDurabilityNotFound(value)
+            | Error::TransactionModeNotFound(value)
+            | Error::TransactionOpenFailed(value)
+            | Error::UpdateFailed(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "builder")]
+fn list_display<T: std::fmt::Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn js_object_display(option: &JsValue) -> String {
+    if option.is_undefined() {
+        "undefined".to_string()
+    } else if option.is_null() {
+        "null".to_string()
+    } else {
+        let object: &Object = option.unchecked_ref();
+        ToString::to_string(&object.to_string())
+    }
+}
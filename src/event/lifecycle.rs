@@ -0,0 +1,138 @@
+#[cfg(feature = "futures")]
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "futures")]
+use futures_core::Stream;
+use num_traits::ToPrimitive;
+use wasm_bindgen::JsCast;
+use web_sys::{DomException, IdbRequest, IdbTransaction, IdbVersionChangeEvent};
+
+#[cfg(feature = "futures")]
+use tokio::sync::mpsc;
+
+#[cfg(feature = "futures")]
+use crate::event::EventListener;
+use crate::Error;
+
+/// An event fired on a [`Database`](crate::Database) connection.
+#[derive(Debug)]
+pub enum DatabaseLifecycleEvent {
+    /// Another connection requested to upgrade or delete the database. The connection should be closed so that the
+    /// request is not blocked.
+    VersionChange {
+        /// Current version of the database
+        old: u32,
+        /// Requested version of the database, or `None` if the database is being deleted
+        new: Option<u32>,
+    },
+    /// The connection was closed unexpectedly (for example, because the database was deleted from the browser's
+    /// settings). This is not fired when [`Database::close`](crate::Database::close) is called.
+    Close,
+    /// A request failed within a transaction of the connection.
+    Error(Error),
+    /// A transaction of the connection was aborted. Contains the reason of the abort, if any.
+    Abort(Option<DomException>),
+}
+
+impl DatabaseLifecycleEvent {
+    /// Converts an event received by a listener added to a [`Database`](crate::Database). Returns `None` if the event
+    /// is not a lifecycle event of a database connection.
+    pub fn from_event(event: &web_sys::Event) -> Option<Self> {
+        match event.type_().as_str() {
+            "versionchange" => {
+                let event = event.dyn_ref::<IdbVersionChangeEvent>()?;
+
+                Some(Self::VersionChange {
+                    old: event.old_version().to_u32()?,
+                    new: event.new_version().and_then(|new| new.to_u32()),
+                })
+            }
+            "close" => Some(Self::Close),
+            "error" => {
+                let target = event.target();
+                let error = target
+                    .as_ref()
+                    .and_then(|target| target.dyn_ref::<IdbRequest>())
+                    .and_then(|request| request.error().ok().flatten())
+                    .or_else(|| {
+                        target
+                            .as_ref()
+                            .and_then(|target| target.dyn_ref::<IdbTransaction>())
+                            .and_then(IdbTransaction::error)
+                    });
+
+                Some(Self::Error(
+                    error.map_or(Error::DomExceptionNotFound, Error::DomException),
+                ))
+            }
+            "abort" => Some(Self::Abort(
+                event
+                    .target()
+                    .and_then(|target| target.dyn_into::<IdbTransaction>().ok())
+                    .and_then(|transaction| transaction.error()),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// A [`Stream`] of the events fired on a [`Database`](crate::Database) connection, returned by
+/// [`Database::events`](crate::Database::events). The stream ends after yielding [`DatabaseLifecycleEvent::Close`].
+#[cfg(feature = "futures")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+#[derive(Debug)]
+pub struct DatabaseEventStream {
+    receiver: mpsc::UnboundedReceiver<DatabaseLifecycleEvent>,
+    listeners: Vec<EventListener>,
+}
+
+#[cfg(feature = "futures")]
+impl DatabaseEventStream {
+    /// Listens for all the lifecycle events of the connection.
+    pub(crate) fn new(target: &web_sys::EventTarget) -> Result<Self, Error> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        let listeners = ["versionchange", "close", "error", "abort"]
+            .into_iter()
+            .map(|event_type| {
+                let sender = sender.clone();
+
+                EventListener::new(target, event_type, move |event| {
+                    if let Some(event) = DatabaseLifecycleEvent::from_event(&event) {
+                        let _ = sender.send(event);
+                    }
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            receiver,
+            listeners,
+        })
+    }
+}
+
+#[cfg(feature = "futures")]
+impl Stream for DatabaseEventStream {
+    type Item = DatabaseLifecycleEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        let event = match this.receiver.poll_recv(cx) {
+            Poll::Ready(event) => event,
+            Poll::Pending => return Poll::Pending,
+        };
+
+        if matches!(event, Some(DatabaseLifecycleEvent::Close)) {
+            // No more events are fired on a closed connection
+            this.receiver.close();
+            this.listeners.clear();
+        }
+
+        Poll::Ready(event)
+    }
+}
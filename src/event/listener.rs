@@ -0,0 +1,69 @@
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::EventTarget;
+
+use crate::Error;
+
+/// A handle to an event listener added using `addEventListener`.
+///
+/// Unlike the `on_*` methods, which replace any previously set handler, any number of listeners can be added for the
+/// same event. The listener is removed when the handle is dropped (or [`EventListener::remove`] is called) unless
+/// [`EventListener::forget`] is called.
+#[derive(Debug)]
+pub struct EventListener {
+    target: EventTarget,
+    event_type: &'static str,
+    callback: Option<Closure<dyn FnMut(web_sys::Event)>>,
+}
+
+impl EventListener {
+    /// Adds a listener for the given event type to the target.
+    pub(crate) fn new<F>(
+        target: &EventTarget,
+        event_type: &'static str,
+        callback: F,
+    ) -> Result<Self, Error>
+    where
+        F: FnMut(web_sys::Event) + 'static,
+    {
+        let callback = Closure::<dyn FnMut(web_sys::Event)>::new(callback);
+
+        target
+            .add_event_listener_with_callback(event_type, callback.as_ref().unchecked_ref())
+            .map_err(Error::EventListenerAddFailed)?;
+
+        Ok(Self {
+            target: target.clone(),
+            event_type,
+            callback: Some(callback),
+        })
+    }
+
+    /// Returns the type of the event the listener was added for.
+    pub fn event_type(&self) -> &str {
+        self.event_type
+    }
+
+    /// Removes the listener. This is equivalent to dropping the handle.
+    pub fn remove(self) {}
+
+    /// Keeps the listener for the lifetime of the target by releasing memory management of the callback to JS GC.
+    ///
+    /// > Note: This may leak memory. Read more about it
+    /// > [here](https://docs.rs/wasm-bindgen/latest/wasm_bindgen/closure/struct.Closure.html#method.into_js_value).
+    pub fn forget(mut self) {
+        if let Some(callback) = self.callback.take() {
+            callback.forget();
+        }
+    }
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        if let Some(callback) = self.callback.take() {
+            let _ = self.target.remove_event_listener_with_callback(
+                self.event_type,
+                callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
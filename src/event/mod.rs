@@ -1,13 +1,20 @@
 //! This module contains the definition of the `Event` trait and its implementations.
 mod database;
+mod lifecycle;
+mod listener;
 mod store;
 
 use crate::Error;
 
+#[cfg(feature = "futures")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+pub use self::lifecycle::DatabaseEventStream;
 pub use self::{
     database::{
         DatabaseEvent, DeleteDatabaseRequestEvent, OpenDatabaseRequestEvent, VersionChangeEvent,
     },
+    lifecycle::DatabaseLifecycleEvent,
+    listener::EventListener,
     store::{
         AddStoreRequestEvent, ClearStoreRequestEvent, CountStoreRequestEvent,
         DeleteStoreRequestEvent, GetAllKeysStoreRequestEvent, GetAllStoreRequestEvent,
@@ -0,0 +1,33 @@
+use js_sys::Reflect;
+use num_traits::ToPrimitive;
+use wasm_bindgen::JsValue;
+
+use crate::Error;
+
+/// Name and version of an existing database, as returned by [`Factory::databases`](crate::Factory::databases).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseInfo {
+    /// Name of the database
+    pub name: String,
+    /// Version of the database
+    pub version: u32,
+}
+
+impl TryFrom<JsValue> for DatabaseInfo {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        let name = Reflect::get(&value, &JsValue::from_str("name"))
+            .ok()
+            .and_then(|name| name.as_string());
+        let version = Reflect::get(&value, &JsValue::from_str("version"))
+            .ok()
+            .and_then(|version| version.as_f64())
+            .and_then(|version| version.to_u32());
+
+        match (name, version) {
+            (Some(name), Some(version)) => Ok(Self { name, version }),
+            _ => Err(Error::UnexpectedJsType("IDBDatabaseInfo", value)),
+        }
+    }
+}
@@ -1,4 +1,12 @@
+mod database_info;
+
+pub use self::database_info::DatabaseInfo;
+
+use std::cmp::Ordering;
+
 use js_sys::Reflect;
+#[cfg(feature = "futures")]
+use js_sys::{Array, Function, Promise};
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::IdbFactory;
 
@@ -44,6 +52,41 @@ impl Factory {
             .map(Into::into)
             .map_err(Error::IndexedDbDeleteFailed)
     }
+
+    /// Returns the names and versions of all the databases available to the current origin.
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    pub async fn databases(&self) -> Result<Vec<DatabaseInfo>, Error> {
+        // `IDBFactory.databases()` is not available in `web-sys`
+        let databases: Function = Reflect::get(&self.inner, &JsValue::from_str("databases"))
+            .map_err(Error::DatabasesListFailed)?
+            .dyn_into()
+            .map_err(Error::DatabasesListFailed)?;
+
+        let promise: Promise = databases
+            .call0(&self.inner)
+            .map_err(Error::DatabasesListFailed)?
+            .dyn_into()
+            .map_err(Error::DatabasesListFailed)?;
+
+        let databases: Array = wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map_err(Error::DatabasesListFailed)?
+            .dyn_into()
+            .map_err(|value| Error::UnexpectedJsType("Array", value))?;
+
+        databases.iter().map(TryInto::try_into).collect()
+    }
+
+    /// Compares two values as keys. Returns an [`Error`] if either of the values is not a valid key.
+    pub fn cmp(&self, first: &JsValue, second: &JsValue) -> Result<Ordering, Error> {
+        let ordering = self
+            .inner
+            .cmp(first, second)
+            .map_err(Error::KeyCompareFailed)?;
+
+        Ok(ordering.cmp(&0))
+    }
 }
 
 impl From<IdbFactory> for Factory {
@@ -0,0 +1,68 @@
+use wasm_bindgen::JsValue;
+
+use crate::{CursorDirection, Error, Query};
+
+/// Options for [`ObjectStore::get_all_with_options`](crate::ObjectStore::get_all_with_options),
+/// [`Index::get_all_with_options`](crate::Index::get_all_with_options) and their `get_all_keys_with_options` and
+/// `get_all_records` counterparts.
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GetAllOptions {
+    /// Key or key range the records must match, or `None` to match all records
+    pub query: Option<Query>,
+    /// Maximum number of records to return, or `None` (or `Some(0)`) to return all matching records
+    pub limit: Option<u32>,
+    /// Order in which the records are returned, [`CursorDirection::Next`] if `None`
+    pub direction: Option<CursorDirection>,
+}
+
+impl GetAllOptions {
+    /// Creates a new instance of [`GetAllOptions`] matching all records in forward order.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns `true` if the records are returned in the order of plain `getAll()` and `getAllKeys()` requests.
+    pub(crate) fn is_forward(&self) -> bool {
+        matches!(self.direction, None | Some(CursorDirection::Next))
+    }
+
+    /// Returns the limit of the records with `None` replaced by `0`, which matches all records.
+    pub(crate) fn limit(&self) -> Option<usize> {
+        self.limit
+            .filter(|limit| *limit > 0)
+            .map(|limit| limit as usize)
+    }
+
+    /// Converts the options into an `IDBGetAllOptions` dictionary.
+    pub(crate) fn to_js_value(&self) -> Result<JsValue, Error> {
+        let options = js_sys::Object::new();
+        let set = |name: &str, value: JsValue| {
+            js_sys::Reflect::set(&options, &JsValue::from_str(name), &value)
+                .map_err(Error::GetAllFailed)
+        };
+
+        if let Some(query) = &self.query {
+            set("query", query.clone().into())?;
+        }
+
+        if let Some(limit) = self.limit {
+            set("count", limit.into())?;
+        }
+
+        if let Some(direction) = self.direction {
+            set("direction", direction.into())?;
+        }
+
+        Ok(options.into())
+    }
+}
+
+impl From<Query> for GetAllOptions {
+    fn from(query: Query) -> Self {
+        Self {
+            query: Some(query),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::marker::PhantomData;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    mappers::{OutputMapper, SerdeOptionMapper, SerdeVecMapper},
+    request::{CountStoreRequest, TypedStoreRequest},
+    utils::to_js_value,
+    Error, Index, Query,
+};
+
+/// An [`Index`] whose keys are of type `K` and whose records have values of type `V`. Keys and values are converted to
+/// and from JS values using `serde`.
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedIndex<K, V> {
+    inner: Index,
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<K, V> TypedIndex<K, V>
+where
+    K: Serialize + DeserializeOwned + 'static,
+    V: Serialize + DeserializeOwned + 'static,
+{
+    /// Returns the name of the index.
+    pub fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    /// Returns the underlying untyped [`Index`].
+    pub fn as_untyped(&self) -> &Index {
+        &self.inner
+    }
+
+    /// Retrieves the value of the first record matching the given key.
+    pub fn get(&self, key: &K) -> Result<TypedStoreRequest<Option<V>>, Error> {
+        self.inner
+            .get(to_js_value(key)?)
+            .map(|request| TypedStoreRequest::new(request, SerdeOptionMapper::<V>::map))
+    }
+
+    /// Retrieves the values of the records matching the given key or key range in query (up to limit if given).
+    pub fn get_all(
+        &self,
+        query: Option<Query>,
+        limit: Option<u32>,
+    ) -> Result<TypedStoreRequest<Vec<V>>, Error> {
+        self.inner
+            .get_all(query, limit)
+            .map(|request| TypedStoreRequest::new(request, SerdeVecMapper::<V>::map))
+    }
+
+    /// Retrieves the primary key of the first record matching the given key. `PK` is the type of the store's keys.
+    pub fn get_key<PK>(&self, key: &K) -> Result<TypedStoreRequest<Option<PK>>, Error>
+    where
+        PK: DeserializeOwned + 'static,
+    {
+        self.inner
+            .get_key(to_js_value(key)?)
+            .map(|request| TypedStoreRequest::new(request, SerdeOptionMapper::<PK>::map))
+    }
+
+    /// Retrieves the primary keys of records matching the given key or key range in query (up to limit if given).
+    /// `PK` is the type of the store's keys.
+    pub fn get_all_keys<PK>(
+        &self,
+        query: Option<Query>,
+        limit: Option<u32>,
+    ) -> Result<TypedStoreRequest<Vec<PK>>, Error>
+    where
+        PK: DeserializeOwned + 'static,
+    {
+        self.inner
+            .get_all_keys(query, limit)
+            .map(|request| TypedStoreRequest::new(request, SerdeVecMapper::<PK>::map))
+    }
+
+    /// Retrieves the number of records matching the given key or key range in query.
+    pub fn count(&self, query: Option<Query>) -> Result<CountStoreRequest, Error> {
+        self.inner.count(query)
+    }
+}
+
+impl<K, V> From<Index> for TypedIndex<K, V> {
+    fn from(inner: Index) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V> From<TypedIndex<K, V>> for Index {
+    fn from(typed: TypedIndex<K, V>) -> Self {
+        typed.inner
+    }
+}
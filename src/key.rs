@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+
+use js_sys::{Array, ArrayBuffer, Date, Reflect, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::Error;
+
+/// Largest absolute time value (in milliseconds) a JS `Date` can represent.
+pub(crate) const MAX_DATE_MILLIS: f64 = 8.64e15;
+
+/// Represents a valid IndexedDB key.
+///
+/// Keys are ordered as defined by the IndexedDB specification: arrays are greater than binary keys, which are greater
+/// than strings, which are greater than dates, which are greater than numbers.
+#[derive(Debug, Clone)]
+pub enum Key {
+    /// A number key (must not be `NaN`)
+    Number(f64),
+    /// A date key, stored as milliseconds since the Unix epoch
+    Date(f64),
+    /// A string key
+    String(String),
+    /// A binary key (an `ArrayBuffer`, typed array or `DataView`)
+    Binary(Vec<u8>),
+    /// An array of keys
+    Array(Vec<Key>),
+}
+
+impl Key {
+    /// Returns `true` if the key is a valid IndexedDB key, i.e., it contains no `NaN` numbers and no invalid dates.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Key::Number(number) => !number.is_nan(),
+            Key::Date(millis) => is_valid_date(*millis),
+            Key::String(_) | Key::Binary(_) => true,
+            Key::Array(keys) => keys.iter().all(Key::is_valid),
+        }
+    }
+
+    fn type_rank(&self) -> u8 {
+        match self {
+            Key::Number(_) => 0,
+            Key::Date(_) => 1,
+            Key::String(_) => 2,
+            Key::Binary(_) => 3,
+            Key::Array(_) => 4,
+        }
+    }
+
+    fn from_js_value(value: &JsValue, seen: &mut Vec<JsValue>) -> Option<Self> {
+        if let Some(number) = value.as_f64() {
+            return (!number.is_nan()).then_some(Key::Number(number));
+        }
+
+        if let Some(string) = value.as_string() {
+            return Some(Key::String(string));
+        }
+
+        if let Some(date) = value.dyn_ref::<Date>() {
+            let millis = date.get_time();
+            return is_valid_date(millis).then_some(Key::Date(millis));
+        }
+
+        if let Some(buffer) = value.dyn_ref::<ArrayBuffer>() {
+            return Some(Key::Binary(Uint8Array::new(buffer).to_vec()));
+        }
+
+        if ArrayBuffer::is_view(value) {
+            let buffer = Reflect::get(value, &JsValue::from_str("buffer")).ok()?;
+            let byte_offset = Reflect::get(value, &JsValue::from_str("byteOffset"))
+                .ok()?
+                .as_f64()?;
+            let byte_length = Reflect::get(value, &JsValue::from_str("byteLength"))
+                .ok()?
+                .as_f64()?;
+
+            let bytes = Uint8Array::new_with_byte_offset_and_length(
+                &buffer,
+                byte_offset as u32,
+                byte_length as u32,
+            );
+
+            return Some(Key::Binary(bytes.to_vec()));
+        }
+
+        if Array::is_array(value) {
+            // Arrays containing themselves are not valid keys
+            if seen.iter().any(|ancestor| ancestor == value) {
+                return None;
+            }
+
+            seen.push(value.clone());
+
+            let array: &Array = value.unchecked_ref();
+            let mut keys = Vec::with_capacity(array.length() as usize);
+
+            for item in array.iter() {
+                keys.push(Self::from_js_value(&item, seen)?);
+            }
+
+            seen.pop();
+
+            return Some(Key::Array(keys));
+        }
+
+        None
+    }
+}
+
+fn is_valid_date(millis: f64) -> bool {
+    !millis.is_nan() && millis.abs() <= MAX_DATE_MILLIS
+}
+
+fn compare_numbers(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Key::Number(a), Key::Number(b)) | (Key::Date(a), Key::Date(b)) => {
+                compare_numbers(*a, *b)
+            }
+            // Strings are compared by their UTF-16 code units
+            (Key::String(a), Key::String(b)) => a.encode_utf16().cmp(b.encode_utf16()),
+            (Key::Binary(a), Key::Binary(b)) => a.cmp(b),
+            (Key::Array(a), Key::Array(b)) => a.cmp(b),
+            _ => self.type_rank().cmp(&other.type_rank()),
+        }
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Key {}
+
+impl TryFrom<JsValue> for Key {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        Self::try_from(&value).map_err(|_| Error::InvalidKey(value))
+    }
+}
+
+impl TryFrom<&JsValue> for Key {
+    type Error = Error;
+
+    fn try_from(value: &JsValue) -> Result<Self, Self::Error> {
+        Self::from_js_value(value, &mut Vec::new()).ok_or_else(|| Error::InvalidKey(value.clone()))
+    }
+}
+
+impl From<&Key> for JsValue {
+    fn from(key: &Key) -> Self {
+        match key {
+            Key::Number(number) => JsValue::from_f64(*number),
+            Key::Date(millis) => Date::new(&JsValue::from_f64(*millis)).into(),
+            Key::String(string) => JsValue::from_str(string),
+            Key::Binary(bytes) => Uint8Array::from(bytes.as_slice()).buffer().into(),
+            Key::Array(keys) => keys.iter().map(JsValue::from).collect::<Array>().into(),
+        }
+    }
+}
+
+impl From<Key> for JsValue {
+    fn from(key: Key) -> Self {
+        JsValue::from(&key)
+    }
+}
+
+impl From<f64> for Key {
+    fn from(number: f64) -> Self {
+        Key::Number(number)
+    }
+}
+
+impl From<i32> for Key {
+    fn from(number: i32) -> Self {
+        Key::Number(number.into())
+    }
+}
+
+impl From<u32> for Key {
+    fn from(number: u32) -> Self {
+        Key::Number(number.into())
+    }
+}
+
+impl From<&str> for Key {
+    fn from(string: &str) -> Self {
+        Key::String(string.to_owned())
+    }
+}
+
+impl From<String> for Key {
+    fn from(string: String) -> Self {
+        Key::String(string)
+    }
+}
+
+impl From<Vec<u8>> for Key {
+    fn from(bytes: Vec<u8>) -> Self {
+        Key::Binary(bytes)
+    }
+}
+
+impl From<Vec<Key>> for Key {
+    fn from(keys: Vec<Key>) -> Self {
+        Key::Array(keys)
+    }
+}
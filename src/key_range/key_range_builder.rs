@@ -0,0 +1,80 @@
+use std::ops::Bound;
+
+use crate::{Error, Key, KeyRange};
+
+/// Builder for a [`KeyRange`] with typed bounds.
+///
+/// ```rust
+/// use idb::{Error, KeyRange};
+///
+/// fn ranges() -> Result<(), Error> {
+///     // Keys from 1 (inclusive) to 10 (exclusive)
+///     let range = KeyRange::from_key(1).to_exclusive(10)?;
+///
+///     // Keys greater than "m"
+///     let range = KeyRange::builder().lower_exclusive("m").build()?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRangeBuilder {
+    lower: Bound<Key>,
+    upper: Bound<Key>,
+}
+
+impl KeyRangeBuilder {
+    /// Creates a new [`KeyRangeBuilder`] without any bounds.
+    pub fn new() -> Self {
+        Self {
+            lower: Bound::Unbounded,
+            upper: Bound::Unbounded,
+        }
+    }
+
+    /// Sets an inclusive lower bound.
+    pub fn lower(mut self, lower: impl Into<Key>) -> Self {
+        self.lower = Bound::Included(lower.into());
+        self
+    }
+
+    /// Sets an exclusive lower bound.
+    pub fn lower_exclusive(mut self, lower: impl Into<Key>) -> Self {
+        self.lower = Bound::Excluded(lower.into());
+        self
+    }
+
+    /// Sets an inclusive upper bound.
+    pub fn upper(mut self, upper: impl Into<Key>) -> Self {
+        self.upper = Bound::Included(upper.into());
+        self
+    }
+
+    /// Sets an exclusive upper bound.
+    pub fn upper_exclusive(mut self, upper: impl Into<Key>) -> Self {
+        self.upper = Bound::Excluded(upper.into());
+        self
+    }
+
+    /// Sets an inclusive upper bound and builds the [`KeyRange`].
+    pub fn to(self, upper: impl Into<Key>) -> Result<KeyRange, Error> {
+        self.upper(upper).build()
+    }
+
+    /// Sets an exclusive upper bound and builds the [`KeyRange`].
+    pub fn to_exclusive(self, upper: impl Into<Key>) -> Result<KeyRange, Error> {
+        self.upper_exclusive(upper).build()
+    }
+
+    /// Builds the [`KeyRange`]. Returns an [`Error`] if neither bound is set or if the lower bound is greater than the
+    /// upper bound.
+    pub fn build(self) -> Result<KeyRange, Error> {
+        KeyRange::from_bounds(self.lower, self.upper)
+    }
+}
+
+impl Default for KeyRangeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
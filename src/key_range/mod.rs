@@ -0,0 +1,270 @@
+mod key_range_builder;
+
+pub use self::key_range_builder::KeyRangeBuilder;
+
+use std::ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+use js_sys::JsString;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::IdbKeyRange;
+
+use crate::{key::MAX_DATE_MILLIS, Error, Key};
+
+/// Represents a continuous interval over some data type that is used for keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRange {
+    inner: IdbKeyRange,
+}
+
+impl KeyRange {
+    /// Returns a new [`KeyRange`] spanning only key.
+    pub fn only(value: impl Into<JsValue>) -> Result<Self, Error> {
+        let inner = IdbKeyRange::only(&value.into()).map_err(Error::KeyRangeCreateFailed)?;
+
+        Ok(Self { inner })
+    }
+
+    /// Returns a new [`KeyRange`] spanning from lower to upper. If `lower_open` is true, `lower` is not included in the
+    /// range. If `upper_open` is true, `upper` is not included in the range.
+    pub fn bound(
+        lower: impl Into<JsValue>,
+        upper: impl Into<JsValue>,
+        lower_open: Option<bool>,
+        upper_open: Option<bool>,
+    ) -> Result<Self, Error> {
+        let inner = IdbKeyRange::bound_with_lower_open_and_upper_open(
+            &lower.into(),
+            &upper.into(),
+            lower_open.unwrap_or_default(),
+            upper_open.unwrap_or_default(),
+        )
+        .map_err(Error::KeyRangeCreateFailed)?;
+
+        Ok(Self { inner })
+    }
+
+    /// Returns a new [`KeyRange`] starting at key with no upper bound. If `lower_open` is true, key is not included in
+    /// the range.
+    pub fn lower_bound(lower: impl Into<JsValue>, lower_open: Option<bool>) -> Result<Self, Error> {
+        let inner =
+            IdbKeyRange::lower_bound_with_open(&lower.into(), lower_open.unwrap_or_default())
+                .map_err(Error::KeyRangeCreateFailed)?;
+
+        Ok(Self { inner })
+    }
+
+    /// Returns a new [`KeyRange`] with no lower bound and ending at key. If `upper_open` is true, key is not included
+    /// in the range.
+    pub fn upper_bound(upper: impl Into<JsValue>, upper_open: Option<bool>) -> Result<Self, Error> {
+        let inner =
+            IdbKeyRange::upper_bound_with_open(&upper.into(), upper_open.unwrap_or_default())
+                .map_err(Error::KeyRangeCreateFailed)?;
+
+        Ok(Self { inner })
+    }
+
+    /// Returns a new [`KeyRangeBuilder`] without any bounds.
+    pub fn builder() -> KeyRangeBuilder {
+        KeyRangeBuilder::new()
+    }
+
+    /// Returns a new [`KeyRangeBuilder`] with an inclusive lower bound at key.
+    pub fn from_key(lower: impl Into<Key>) -> KeyRangeBuilder {
+        KeyRangeBuilder::new().lower(lower)
+    }
+
+    /// Returns a new [`KeyRange`] spanning all the keys which start with the given prefix. The prefix must be a string
+    /// (matching strings starting with it) or an array (matching arrays whose first items are equal to it).
+    pub fn starts_with(prefix: impl Into<Key>) -> Result<Self, Error> {
+        match prefix.into() {
+            Key::String(prefix) => {
+                // Strings are compared by their UTF-16 code units, so the first string after all the strings starting
+                // with the prefix is the prefix with its last code unit (that can be incremented) incremented.
+                let mut upper: Vec<u16> = prefix.encode_utf16().collect();
+
+                while upper.last() == Some(&u16::MAX) {
+                    upper.pop();
+                }
+
+                match upper.last_mut() {
+                    Some(unit) => {
+                        *unit += 1;
+                        Self::bound(prefix, JsString::from_char_code(&upper), None, Some(true))
+                    }
+                    // Binary keys are greater than all strings
+                    None => Self::bound(prefix, Key::Binary(Vec::new()), None, Some(true)),
+                }
+            }
+            Key::Array(prefix) => {
+                let mut upper = prefix.clone();
+
+                match upper.pop() {
+                    Some(last) => {
+                        upper.push(successor(last));
+                        Self::bound(Key::Array(prefix), Key::Array(upper), None, Some(true))
+                    }
+                    // Arrays are greater than all other keys
+                    None => Self::lower_bound(Key::Array(prefix), None),
+                }
+            }
+            _ => Err(Error::InvalidKeyRange(
+                "prefix must be a string or an array key",
+            )),
+        }
+    }
+
+    /// Returns a new [`KeyRange`] with the given bounds.
+    pub(crate) fn from_bounds(lower: Bound<Key>, upper: Bound<Key>) -> Result<Self, Error> {
+        match (bound_parts(lower), bound_parts(upper)) {
+            (None, None) => Err(Error::InvalidKeyRange(
+                "key range must have at least one bound",
+            )),
+            (Some((lower, lower_open)), None) => Self::lower_bound(lower, Some(lower_open)),
+            (None, Some((upper, upper_open))) => Self::upper_bound(upper, Some(upper_open)),
+            (Some((lower, lower_open)), Some((upper, upper_open))) => {
+                Self::bound(lower, upper, Some(lower_open), Some(upper_open))
+            }
+        }
+    }
+
+    /// Returns the range’s lower bound, or `None` if none.
+    pub fn lower(&self) -> Result<Option<Key>, Error> {
+        let lower = self.inner.lower().map_err(Error::KeyRangeBoundNotFound)?;
+
+        if lower.is_undefined() {
+            Ok(None)
+        } else {
+            lower.try_into().map(Some)
+        }
+    }
+
+    /// Returns the range’s upper bound, or `None` if none.
+    pub fn upper(&self) -> Result<Option<Key>, Error> {
+        let upper = self.inner.upper().map_err(Error::KeyRangeBoundNotFound)?;
+
+        if upper.is_undefined() {
+            Ok(None)
+        } else {
+            upper.try_into().map(Some)
+        }
+    }
+
+    /// Returns the range’s lower open flag.
+    pub fn lower_open(&self) -> bool {
+        self.inner.lower_open()
+    }
+
+    /// Returns the range’s upper open flag.
+    pub fn upper_open(&self) -> bool {
+        self.inner.upper_open()
+    }
+
+    /// Returns true if key is included in the range, and false otherwise.
+    pub fn includes(&self, value: impl Into<JsValue>) -> Result<bool, Error> {
+        self.inner
+            .includes(&value.into())
+            .map_err(Error::KeyRangeIncludesFailed)
+    }
+}
+
+impl<K: Into<Key>> TryFrom<Range<K>> for KeyRange {
+    type Error = Error;
+
+    fn try_from(range: Range<K>) -> Result<Self, Self::Error> {
+        Self::from_key(range.start).to_exclusive(range.end)
+    }
+}
+
+impl<K: Into<Key>> TryFrom<RangeInclusive<K>> for KeyRange {
+    type Error = Error;
+
+    fn try_from(range: RangeInclusive<K>) -> Result<Self, Self::Error> {
+        let (start, end) = range.into_inner();
+        Self::from_key(start).to(end)
+    }
+}
+
+impl<K: Into<Key>> TryFrom<RangeFrom<K>> for KeyRange {
+    type Error = Error;
+
+    fn try_from(range: RangeFrom<K>) -> Result<Self, Self::Error> {
+        Self::from_key(range.start).build()
+    }
+}
+
+impl<K: Into<Key>> TryFrom<RangeTo<K>> for KeyRange {
+    type Error = Error;
+
+    fn try_from(range: RangeTo<K>) -> Result<Self, Self::Error> {
+        Self::builder().upper_exclusive(range.end).build()
+    }
+}
+
+impl<K: Into<Key>> TryFrom<RangeToInclusive<K>> for KeyRange {
+    type Error = Error;
+
+    fn try_from(range: RangeToInclusive<K>) -> Result<Self, Self::Error> {
+        Self::builder().upper(range.end).build()
+    }
+}
+
+/// Returns the key of a [`Bound`] and whether it is open, or `None` if it is unbounded.
+fn bound_parts(bound: Bound<Key>) -> Option<(Key, bool)> {
+    match bound {
+        Bound::Included(key) => Some((key, false)),
+        Bound::Excluded(key) => Some((key, true)),
+        Bound::Unbounded => None,
+    }
+}
+
+/// Returns the smallest key greater than the given key.
+fn successor(key: Key) -> Key {
+    match key {
+        Key::Number(number) if number == f64::INFINITY => Key::Date(-MAX_DATE_MILLIS),
+        Key::Number(number) => Key::Number(number.next_up()),
+        // Dates have a millisecond precision
+        Key::Date(millis) if millis >= MAX_DATE_MILLIS => Key::String(String::new()),
+        Key::Date(millis) => Key::Date(millis.floor() + 1.0),
+        Key::String(mut string) => {
+            string.push('\0');
+            Key::String(string)
+        }
+        Key::Binary(mut bytes) => {
+            bytes.push(0);
+            Key::Binary(bytes)
+        }
+        Key::Array(mut keys) => {
+            keys.push(Key::Number(f64::NEG_INFINITY));
+            Key::Array(keys)
+        }
+    }
+}
+
+impl From<IdbKeyRange> for KeyRange {
+    fn from(inner: IdbKeyRange) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<KeyRange> for IdbKeyRange {
+    fn from(key_range: KeyRange) -> Self {
+        key_range.inner
+    }
+}
+
+impl TryFrom<JsValue> for KeyRange {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        value
+            .dyn_into::<IdbKeyRange>()
+            .map(Into::into)
+            .map_err(|value| Error::UnexpectedJsType("IdbKeyRange", value))
+    }
+}
+
+impl From<KeyRange> for JsValue {
+    fn from(value: KeyRange) -> Self {
+        value.inner.into()
+    }
+}
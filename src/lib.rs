@@ -20,6 +20,42 @@
 //! After disabling the `futures` feature, you can use `on_success` and `on_error` methods on requests to attach
 //! callbacks.
 //!
+//! To read and write values as Rust types (using `serde`) instead of raw `JsValue`s, you can enable the `serde`
+//! feature and use [`TypedObjectStore`] and [`TypedIndex`]:
+//!
+//! ```sh
+//! cargo add idb --features serde
+//! ```
+//!
+//! To run the same code natively (for example, in `cargo test`) as well as in the browser, you can enable the
+//! `memory` feature and write it against the traits in the [`backend`] module, which are implemented by the browser
+//! types and by a pure Rust in-memory database:
+//!
+//! ```sh
+//! cargo add idb --features memory
+//! ```
+//!
+//! IndexedDB does not notify other tabs of the changes made to a database. To keep them in sync, you can enable the
+//! `changes` feature and publish the changes made by committed transactions using a [`ChangeFeed`]:
+//!
+//! ```sh
+//! cargo add idb --features changes
+//! ```
+//!
+//! To back up a whole database (its schema and records) as JSON Lines and restore it later, you can enable the `backup`
+//! feature and use the functions in the [`backup`] module:
+//!
+//! ```sh
+//! cargo add idb --features backup
+//! ```
+//!
+//! To declare object stores and their indexes next to the Rust types stored in them, you can enable the `derive`
+//! feature and derive [`IdbStore`] for your record structs:
+//!
+//! ```sh
+//! cargo add idb --features derive
+//! ```
+//!
 //! ## Example
 //!
 //! To create a new database, you can use [`Factory::open`]:
@@ -133,39 +169,77 @@
 //!
 //! For more examples on using other functionality, see the
 //! [tests](https://github.com/devashishdxt/idb/tree/main/idb/tests) directory.
+#[cfg(feature = "memory")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "memory")))]
+pub mod backend;
+#[cfg(feature = "backup")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "backup")))]
+pub mod backup;
 #[cfg(feature = "builder")]
 #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "builder")))]
 pub mod builder;
+#[cfg(feature = "changes")]
+mod changes;
 mod cursor;
 mod database;
 mod error;
 pub mod event;
 mod factory;
+#[cfg(feature = "futures")]
+mod get_all_options;
 mod index;
+mod key;
 mod key_range;
 mod mappers;
 mod object_store;
+#[cfg(feature = "futures")]
+mod page;
 mod query;
+mod queryable;
+mod record;
 pub mod request;
+mod schema;
+#[cfg(feature = "derive")]
+mod store;
 mod transaction;
 mod utils;
 
+#[cfg(feature = "changes")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "changes")))]
+pub use self::changes::{Change, ChangeFeed, ChangeKind, ChangeStream};
+#[cfg(feature = "derive")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "derive")))]
+pub use self::store::IdbStore;
 pub use self::{
-    cursor::{Cursor, CursorDirection, KeyCursor},
+    cursor::{Cursor, CursorDirection, CursorSource, KeyCursor},
     database::Database,
-    error::Error,
-    event::{DatabaseEvent, Event, StoreEvent},
-    factory::Factory,
+    error::{Error, IdbErrorKind},
+    event::{DatabaseEvent, DatabaseLifecycleEvent, Event, EventListener, StoreEvent},
+    factory::{DatabaseInfo, Factory},
     index::{Index, IndexParams},
-    key_range::KeyRange,
+    key::Key,
+    key_range::{KeyRange, KeyRangeBuilder},
     object_store::{KeyPath, ObjectStore, ObjectStoreParams},
     query::Query,
+    queryable::Queryable,
+    record::Record,
     request::Request,
-    transaction::{Transaction, TransactionMode},
+    schema::{DatabaseSchema, IndexSchema, ObjectStoreSchema},
+    transaction::{Transaction, TransactionDurability, TransactionMode, TransactionOptions},
 };
 #[cfg(feature = "futures")]
 #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
 pub use self::{
-    cursor::{ManagedCursor, ManagedKeyCursor},
+    cursor::{CursorStream, KeyCursorStream, ManagedCursor, ManagedKeyCursor},
+    event::DatabaseEventStream,
+    get_all_options::GetAllOptions,
+    object_store::{BatchFailure, BatchItem, BatchResult},
+    page::{Continuation, Page},
     transaction::{TransactionFuture, TransactionResult},
 };
+#[cfg(feature = "serde")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+pub use self::{index::TypedIndex, object_store::TypedObjectStore};
+#[cfg(feature = "derive")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "derive")))]
+pub use idb_derive::IdbStore;
@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+use std::marker::PhantomData;
+
 use js_sys::Array;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::DomException;
@@ -125,6 +128,56 @@ impl OutputMapper for KeyCursorMapper {
     }
 }
 
+#[cfg(feature = "serde")]
+pub struct SerdeMapper<T>(PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<T> OutputMapper for SerdeMapper<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    type Output = T;
+
+    fn map(value: Result<JsValue, Error>) -> Result<Self::Output, Error> {
+        NullCheckMapper::map(value).and_then(crate::utils::from_js_value)
+    }
+}
+
+#[cfg(feature = "serde")]
+pub struct SerdeOptionMapper<T>(PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<T> OutputMapper for SerdeOptionMapper<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    type Output = Option<T>;
+
+    fn map(value: Result<JsValue, Error>) -> Result<Self::Output, Error> {
+        OptionMapper::map(value)?
+            .map(crate::utils::from_js_value)
+            .transpose()
+    }
+}
+
+#[cfg(feature = "serde")]
+pub struct SerdeVecMapper<T>(PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<T> OutputMapper for SerdeVecMapper<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    type Output = Vec<T>;
+
+    fn map(value: Result<JsValue, Error>) -> Result<Self::Output, Error> {
+        VecMapper::map(value)?
+            .into_iter()
+            .map(crate::utils::from_js_value)
+            .collect()
+    }
+}
+
 pub struct ErrorMapper;
 
 impl ErrorMapper {
@@ -0,0 +1,233 @@
+use std::{cell::RefCell, rc::Rc};
+
+use js_sys::{Array, Reflect};
+use tokio::sync::oneshot;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Event, IdbRequest};
+
+use crate::{mappers::ErrorMapper, request::StoreRequest, Error, KeyPath, Request};
+
+/// A value (and an optional out-of-line key) to write using [`ObjectStore::put_all`](crate::ObjectStore::put_all) or
+/// [`ObjectStore::add_all`](crate::ObjectStore::add_all).
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchItem {
+    /// Value of the record
+    pub value: JsValue,
+    /// Key of the record (only for object stores with out-of-line keys)
+    pub key: Option<JsValue>,
+}
+
+impl BatchItem {
+    /// Creates a new [`BatchItem`] with the given value and key.
+    pub fn new(value: JsValue, key: Option<JsValue>) -> Self {
+        Self { value, key }
+    }
+}
+
+impl From<JsValue> for BatchItem {
+    fn from(value: JsValue) -> Self {
+        Self::new(value, None)
+    }
+}
+
+impl From<(JsValue, JsValue)> for BatchItem {
+    fn from((value, key): (JsValue, JsValue)) -> Self {
+        Self::new(value, Some(key))
+    }
+}
+
+impl From<(JsValue, Option<JsValue>)> for BatchItem {
+    fn from((value, key): (JsValue, Option<JsValue>)) -> Self {
+        Self::new(value, key)
+    }
+}
+
+/// Outcome of a batch of writes issued using [`ObjectStore::put_all`](crate::ObjectStore::put_all),
+/// [`ObjectStore::add_all`](crate::ObjectStore::add_all) or [`ObjectStore::delete_all`](crate::ObjectStore::delete_all).
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+#[derive(Debug, Default)]
+pub struct BatchResult {
+    /// Keys of the items, in the order in which they were given, or `None` for the items which failed. For puts and
+    /// adds, this is the key of the written record. For deletes, this is the deleted key or key range.
+    pub keys: Vec<Option<JsValue>>,
+    /// Items which failed, in the order in which they were given
+    pub failures: Vec<BatchFailure>,
+}
+
+impl BatchResult {
+    /// Returns `true` if none of the items failed.
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Returns the number of items which succeeded.
+    pub fn succeeded(&self) -> usize {
+        self.keys.len() - self.failures.len()
+    }
+}
+
+/// An item of a batch which failed.
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+#[derive(Debug)]
+pub struct BatchFailure {
+    /// Position of the item in the batch
+    pub index: usize,
+    /// Key of the item, if known. For puts and adds, this is the out-of-line key of the item or the key at the key
+    /// path of the object store. For deletes, this is the key or key range.
+    pub key: Option<JsValue>,
+    /// Error raised by the request
+    pub error: Error,
+}
+
+/// Collects the outcome of the requests of a batch.
+#[derive(Debug, Default)]
+struct BatchState {
+    result: BatchResult,
+    /// Number of issued requests which have not completed yet
+    pending: usize,
+    /// Sender notified once all the requests have been issued and completed
+    done: Option<oneshot::Sender<()>>,
+}
+
+impl BatchState {
+    /// Records a failure, keeping the failures ordered by index (requests may fail synchronously or asynchronously).
+    fn fail(&mut self, index: usize, key: Option<JsValue>, error: Error) {
+        let position = self
+            .result
+            .failures
+            .partition_point(|failure| failure.index < index);
+        self.result
+            .failures
+            .insert(position, BatchFailure { index, key, error });
+    }
+
+    fn complete(&mut self) {
+        self.pending -= 1;
+
+        if self.pending == 0 {
+            if let Some(done) = self.done.take() {
+                let _ = done.send(());
+            }
+        }
+    }
+}
+
+/// Issues a request for every item using `issue` and waits for all of them to complete. Failed requests do not abort
+/// the transaction; they are reported in the returned [`BatchResult`] instead.
+pub(crate) async fn run_batch<T>(
+    items: impl IntoIterator<Item = T>,
+    key_of: impl Fn(&T) -> Option<JsValue>,
+    issue: impl Fn(&T) -> Result<StoreRequest, Error>,
+) -> Result<BatchResult, Error> {
+    let state = Rc::new(RefCell::new(BatchState::default()));
+    let mut requests = Vec::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+        let key = key_of(&item);
+        state.borrow_mut().result.keys.push(None);
+
+        let mut request = match issue(&item) {
+            Ok(request) => request,
+            Err(err) => {
+                state.borrow_mut().fail(index, key, err);
+                continue;
+            }
+        };
+
+        state.borrow_mut().pending += 1;
+
+        let success_state = state.clone();
+        let success_key = key.clone();
+        request.on_success(move |event| {
+            let mut state = success_state.borrow_mut();
+            match request_of(&event).and_then(|request| request.result()) {
+                // Delete requests succeed with `undefined`
+                Ok(result) if result.is_undefined() => state.result.keys[index] = success_key,
+                Ok(result) => state.result.keys[index] = Some(result),
+                Err(err) => state.fail(index, success_key, err),
+            }
+            state.complete();
+        });
+
+        let error_state = state.clone();
+        let error_key = key.clone();
+        request.on_error(move |event| {
+            // Handle the error so that it does not abort the transaction
+            event.prevent_default();
+
+            let error = match request_of(&event) {
+                Ok(request) => ErrorMapper::map(request.error()),
+                Err(err) => err,
+            };
+
+            let mut state = error_state.borrow_mut();
+            state.fail(index, error_key, error);
+            state.complete();
+        });
+
+        requests.push(request);
+    }
+
+    let receiver = {
+        let mut state = state.borrow_mut();
+
+        if state.pending == 0 {
+            None
+        } else {
+            let (sender, receiver) = oneshot::channel();
+            state.done = Some(sender);
+            Some(receiver)
+        }
+    };
+
+    if let Some(receiver) = receiver {
+        receiver
+            .await
+            .map_err(|_| Error::OneshotChannelReceiveError)?;
+    }
+
+    // Callbacks of the requests are only dropped once all of them have completed
+    drop(requests);
+
+    let mut state = state.borrow_mut();
+    Ok(std::mem::take(&mut state.result))
+}
+
+/// Returns the key of the value at the given key path, if it exists.
+pub(crate) fn key_from_value(value: &JsValue, key_path: &KeyPath) -> Option<JsValue> {
+    match key_path {
+        KeyPath::Single(path) => value_at_path(value, path),
+        KeyPath::Array(paths) => paths
+            .iter()
+            .map(|path| value_at_path(value, path))
+            .collect::<Option<Array>>()
+            .map(Into::into),
+    }
+}
+
+fn value_at_path(value: &JsValue, path: &str) -> Option<JsValue> {
+    if path.is_empty() {
+        return Some(value.clone());
+    }
+
+    path.split('.')
+        .try_fold(value.clone(), |value, identifier| {
+            if !value.is_object() {
+                return None;
+            }
+
+            Reflect::get(&value, &JsValue::from_str(identifier))
+                .ok()
+                .filter(|value| !value.is_undefined())
+        })
+}
+
+fn request_of(event: &Event) -> Result<StoreRequest, Error> {
+    event
+        .target()
+        .ok_or(Error::EventTargetNotFound)?
+        .dyn_into::<IdbRequest>()
+        .map(Into::into)
+        .map_err(|target| Error::UnexpectedJsType("IdbRequest", target.into()))
+}
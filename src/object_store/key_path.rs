@@ -5,6 +5,8 @@ use crate::Error;
 
 /// Represents key path of an object store
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum KeyPath {
     /// Key path with single key
     Single(String),
@@ -1,6 +1,14 @@
+#[cfg(feature = "futures")]
+mod batch;
 mod key_path;
 mod object_store_params;
+#[cfg(feature = "serde")]
+mod typed_object_store;
 
+#[cfg(feature = "futures")]
+pub use self::batch::{BatchFailure, BatchItem, BatchResult};
+#[cfg(feature = "serde")]
+pub use self::typed_object_store::TypedObjectStore;
 pub use self::{key_path::KeyPath, object_store_params::ObjectStoreParams};
 
 use wasm_bindgen::{JsCast, JsValue};
@@ -9,14 +17,13 @@ use web_sys::IdbObjectStore;
 #[cfg(feature = "builder")]
 use crate::builder::ObjectStoreBuilder;
 use crate::{
-    request::{
-        AddStoreRequest, ClearStoreRequest, CountStoreRequest, DeleteStoreRequest,
-        GetAllKeysStoreRequest, GetAllStoreRequest, GetKeyStoreRequest, GetStoreRequest,
-        OpenCursorStoreRequest, OpenKeyCursorStoreRequest, PutStoreRequest,
-    },
+    queryable::impl_queryable,
+    request::{AddStoreRequest, ClearStoreRequest, DeleteStoreRequest, PutStoreRequest},
     utils::dom_string_list_to_vec,
-    CursorDirection, Error, Index, IndexParams, Query, Transaction,
+    Error, Index, IndexParams, ObjectStoreSchema, Query, Transaction,
 };
+#[cfg(feature = "changes")]
+use crate::{ChangeKind, Key};
 
 /// Represents an object store in a database.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -68,194 +75,135 @@ impl ObjectStore {
         self.inner.auto_increment()
     }
 
+    /// Returns a snapshot of the schema of the store and its indexes.
+    pub fn schema(&self) -> Result<ObjectStoreSchema, Error> {
+        let indexes = self
+            .index_names()
+            .iter()
+            .map(|name| self.index(name)?.schema())
+            .collect::<Result<_, _>>()?;
+
+        Ok(ObjectStoreSchema {
+            name: self.name(),
+            key_path: self.key_path()?,
+            auto_increment: self.auto_increment(),
+            indexes,
+        })
+    }
+
     /// Adds or updates a record in store with the given value and key.
     pub fn put(&self, value: &JsValue, key: Option<&JsValue>) -> Result<PutStoreRequest, Error> {
-        match key {
+        let request = match key {
             None => self.inner.put(value),
             Some(key) => self.inner.put_with_key(value, key),
         }
-        .map(Into::into)
-        .map_err(Error::UpdateFailed)
+        .map_err(Error::UpdateFailed)?;
+
+        #[cfg(feature = "changes")]
+        crate::changes::record(&request, &self.inner, ChangeKind::Put, None);
+
+        Ok(request.into())
     }
 
     /// Adds a record in store with the given value and key.
     pub fn add(&self, value: &JsValue, key: Option<&JsValue>) -> Result<AddStoreRequest, Error> {
-        match key {
+        let request = match key {
             None => self.inner.add(value),
             Some(key) => self.inner.add_with_key(value, key),
         }
-        .map(Into::into)
-        .map_err(Error::AddFailed)
-    }
+        .map_err(Error::AddFailed)?;
 
-    /// Deletes records in store with the given key or in the given key range in query.
-    pub fn delete(&self, query: impl Into<Query>) -> Result<DeleteStoreRequest, Error> {
-        self.inner
-            .delete(&query.into().into())
-            .map(Into::into)
-            .map_err(Error::DeleteFailed)
-    }
+        #[cfg(feature = "changes")]
+        crate::changes::record(&request, &self.inner, ChangeKind::Add, None);
 
-    /// Deletes all records in store.
-    pub fn clear(&self) -> Result<ClearStoreRequest, Error> {
-        self.inner
-            .clear()
-            .map(Into::into)
-            .map_err(Error::ClearFailed)
+        Ok(request.into())
     }
 
-    /// Retrieves the value of the first record matching the given key or key range in query.
-    pub fn get(&self, query: impl Into<Query>) -> Result<GetStoreRequest, Error> {
-        self.inner
-            .get(&query.into().into())
-            .map(Into::into)
-            .map_err(Error::GetFailed)
-    }
+    /// Adds or updates a record in store for every item (a value or a value/key pair) and waits for all the requests
+    /// to complete. Failed items do not abort the transaction and are reported in the returned [`BatchResult`].
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    pub async fn put_all<I>(&self, items: I) -> Result<BatchResult, Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<BatchItem>,
+    {
+        let key_path = self.key_path()?;
 
-    /// Retrieves the key of the first record matching the given key or key range in query.
-    pub fn get_key(&self, query: impl Into<Query>) -> Result<GetKeyStoreRequest, Error> {
-        self.inner
-            .get_key(&query.into().into())
-            .map(Into::into)
-            .map_err(Error::GetKeyFailed)
+        batch::run_batch(
+            items.into_iter().map(Into::into),
+            |item| item_key(item, key_path.as_ref()),
+            |item| self.put(&item.value, item.key.as_ref()).map(Into::into),
+        )
+        .await
     }
 
-    /// Retrieves the values of the records matching the given key or key range in query (up to limit if given).
-    pub fn get_all(
-        &self,
-        query: Option<Query>,
-        limit: Option<u32>,
-    ) -> Result<GetAllStoreRequest, Error> {
-        match (query, limit) {
-            (Some(query), Some(limit)) => self
-                .inner
-                .get_all_with_key_and_limit(&query.into(), limit)
-                .map(Into::into)
-                .map_err(Error::GetAllKeysFailed),
-            (Some(query), None) => self
-                .inner
-                .get_all_with_key(&query.into())
-                .map(Into::into)
-                .map_err(Error::GetAllKeysFailed),
-            (None, Some(limit)) => self
-                .inner
-                .get_all_with_key_and_limit(&JsValue::null(), limit)
-                .map(Into::into)
-                .map_err(Error::GetAllKeysFailed),
-            (None, None) => self
-                .inner
-                .get_all()
-                .map(Into::into)
-                .map_err(Error::GetAllKeysFailed),
-        }
-    }
+    /// Adds a record in store for every item (a value or a value/key pair) and waits for all the requests to
+    /// complete. Failed items (e.g., items whose key already exists) do not abort the transaction and are reported in
+    /// the returned [`BatchResult`].
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    pub async fn add_all<I>(&self, items: I) -> Result<BatchResult, Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<BatchItem>,
+    {
+        let key_path = self.key_path()?;
 
-    /// Retrieves the keys of records matching the given key or key range in query (up to limit if given).
-    pub fn get_all_keys(
-        &self,
-        query: Option<Query>,
-        limit: Option<u32>,
-    ) -> Result<GetAllKeysStoreRequest, Error> {
-        match (query, limit) {
-            (Some(query), Some(limit)) => self
-                .inner
-                .get_all_keys_with_key_and_limit(&query.into(), limit)
-                .map(Into::into)
-                .map_err(Error::GetAllKeysFailed),
-            (Some(query), None) => self
-                .inner
-                .get_all_keys_with_key(&query.into())
-                .map(Into::into)
-                .map_err(Error::GetAllKeysFailed),
-            (None, Some(limit)) => self
-                .inner
-                .get_all_keys_with_key_and_limit(&JsValue::null(), limit)
-                .map(Into::into)
-                .map_err(Error::GetAllKeysFailed),
-            (None, None) => self
-                .inner
-                .get_all_keys()
-                .map(Into::into)
-                .map_err(Error::GetAllKeysFailed),
-        }
+        batch::run_batch(
+            items.into_iter().map(Into::into),
+            |item| item_key(item, key_path.as_ref()),
+            |item| self.add(&item.value, item.key.as_ref()).map(Into::into),
+        )
+        .await
     }
 
-    /// Retrieves the number of records matching the given key or key range in query.
-    pub fn count(&self, query: Option<Query>) -> Result<CountStoreRequest, Error> {
-        match query {
-            None => self
-                .inner
-                .count()
-                .map(Into::into)
-                .map_err(Error::CountFailed),
-            Some(query) => self
-                .inner
-                .count_with_key(&query.into())
-                .map(Into::into)
-                .map_err(Error::CountFailed),
-        }
+    /// Deletes the records in store with every given key or key range and waits for all the requests to complete.
+    /// Failed items do not abort the transaction and are reported in the returned [`BatchResult`].
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    pub async fn delete_all<I>(&self, queries: I) -> Result<BatchResult, Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<Query>,
+    {
+        batch::run_batch(
+            queries.into_iter().map(|query| JsValue::from(query.into())),
+            |query| Some(query.clone()),
+            |query| self.delete(query.clone()).map(Into::into),
+        )
+        .await
     }
 
-    /// Opens a [`Cursor`](crate::Cursor) over the records matching query, ordered by direction. If query is `None`,
-    /// all records in store are matched.
-    pub fn open_cursor(
-        &self,
-        query: Option<Query>,
-        cursor_direction: Option<CursorDirection>,
-    ) -> Result<OpenCursorStoreRequest, Error> {
-        match (query, cursor_direction) {
-            (Some(query), Some(cursor_direction)) => self
-                .inner
-                .open_cursor_with_range_and_direction(&query.into(), cursor_direction.into())
-                .map(Into::into)
-                .map_err(Error::OpenCursorFailed),
-            (Some(query), None) => self
-                .inner
-                .open_cursor_with_range(&query.into())
-                .map(Into::into)
-                .map_err(Error::OpenCursorFailed),
-            (None, Some(cursor_direction)) => self
-                .inner
-                .open_cursor_with_range_and_direction(&JsValue::null(), cursor_direction.into())
-                .map(Into::into)
-                .map_err(Error::OpenCursorFailed),
-            (None, None) => self
-                .inner
-                .open_cursor()
-                .map(Into::into)
-                .map_err(Error::OpenCursorFailed),
-        }
+    /// Deletes records in store with the given key or in the given key range in query.
+    pub fn delete(&self, query: impl Into<Query>) -> Result<DeleteStoreRequest, Error> {
+        let query = query.into();
+
+        #[cfg(feature = "changes")]
+        let key = match &query {
+            Query::Key(key) => Key::try_from(key).ok(),
+            Query::KeyRange(_) => None,
+        };
+
+        let request = self
+            .inner
+            .delete(&query.into())
+            .map_err(Error::DeleteFailed)?;
+
+        #[cfg(feature = "changes")]
+        crate::changes::record(&request, &self.inner, ChangeKind::Delete, key);
+
+        Ok(request.into())
     }
 
-    /// Opens a [`KeyCursor`](crate::KeyCursor) over the records matching query, ordered by direction. If query is
-    /// `None`, all records in store are matched.
-    pub fn open_key_cursor(
-        &self,
-        query: Option<Query>,
-        cursor_direction: Option<CursorDirection>,
-    ) -> Result<OpenKeyCursorStoreRequest, Error> {
-        match (query, cursor_direction) {
-            (Some(query), Some(cursor_direction)) => self
-                .inner
-                .open_key_cursor_with_range_and_direction(&query.into(), cursor_direction.into())
-                .map(Into::into)
-                .map_err(Error::OpenCursorFailed),
-            (Some(query), None) => self
-                .inner
-                .open_key_cursor_with_range(&query.into())
-                .map(Into::into)
-                .map_err(Error::OpenCursorFailed),
-            (None, Some(cursor_direction)) => self
-                .inner
-                .open_key_cursor_with_range_and_direction(&JsValue::null(), cursor_direction.into())
-                .map(Into::into)
-                .map_err(Error::OpenCursorFailed),
-            (None, None) => self
-                .inner
-                .open_key_cursor()
-                .map(Into::into)
-                .map_err(Error::OpenCursorFailed),
-        }
+    /// Deletes all records in store.
+    pub fn clear(&self) -> Result<ClearStoreRequest, Error> {
+        let request = self.inner.clear().map_err(Error::ClearFailed)?;
+
+        #[cfg(feature = "changes")]
+        crate::changes::record(&request, &self.inner, ChangeKind::Clear, None);
+
+        Ok(request.into())
     }
 
     /// Returns an [`Index`] for the index named name in store.
@@ -299,6 +247,18 @@ impl ObjectStore {
     }
 }
 
+/// Returns the key of a batch item: its out-of-line key or the key at the key path of the store.
+#[cfg(feature = "futures")]
+fn item_key(item: &BatchItem, key_path: Option<&KeyPath>) -> Option<JsValue> {
+    match (&item.key, key_path) {
+        (Some(key), _) => Some(key.clone()),
+        (None, Some(key_path)) => batch::key_from_value(&item.value, key_path),
+        (None, None) => None,
+    }
+}
+
+impl_queryable!(ObjectStore, "store", false);
+
 impl From<IdbObjectStore> for ObjectStore {
     fn from(inner: IdbObjectStore) -> Self {
         Self { inner }
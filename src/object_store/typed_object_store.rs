@@ -0,0 +1,122 @@
+use std::marker::PhantomData;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    mappers::{OutputMapper, SerdeMapper, SerdeOptionMapper, SerdeVecMapper},
+    request::{ClearStoreRequest, CountStoreRequest, DeleteStoreRequest, TypedStoreRequest},
+    utils::to_js_value,
+    Error, ObjectStore, Query, TypedIndex,
+};
+
+/// An [`ObjectStore`] whose keys are of type `K` and values are of type `V`. Keys and values are converted to and from
+/// JS values using `serde`.
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedObjectStore<K, V> {
+    inner: ObjectStore,
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<K, V> TypedObjectStore<K, V>
+where
+    K: Serialize + DeserializeOwned + 'static,
+    V: Serialize + DeserializeOwned + 'static,
+{
+    /// Returns the name of the store.
+    pub fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    /// Returns the underlying untyped [`ObjectStore`].
+    pub fn as_untyped(&self) -> &ObjectStore {
+        &self.inner
+    }
+
+    /// Adds or updates a record in store with the given value and key.
+    pub fn put(&self, value: &V, key: Option<&K>) -> Result<TypedStoreRequest<K>, Error> {
+        let value = to_js_value(value)?;
+        let key = key.map(to_js_value).transpose()?;
+
+        self.inner
+            .put(&value, key.as_ref())
+            .map(|request| TypedStoreRequest::new(request, SerdeMapper::<K>::map))
+    }
+
+    /// Adds a record in store with the given value and key.
+    pub fn add(&self, value: &V, key: Option<&K>) -> Result<TypedStoreRequest<K>, Error> {
+        let value = to_js_value(value)?;
+        let key = key.map(to_js_value).transpose()?;
+
+        self.inner
+            .add(&value, key.as_ref())
+            .map(|request| TypedStoreRequest::new(request, SerdeMapper::<K>::map))
+    }
+
+    /// Deletes the record in store with the given key.
+    pub fn delete(&self, key: &K) -> Result<DeleteStoreRequest, Error> {
+        self.inner.delete(to_js_value(key)?)
+    }
+
+    /// Deletes all records in store.
+    pub fn clear(&self) -> Result<ClearStoreRequest, Error> {
+        self.inner.clear()
+    }
+
+    /// Retrieves the value of the record with the given key.
+    pub fn get(&self, key: &K) -> Result<TypedStoreRequest<Option<V>>, Error> {
+        self.inner
+            .get(to_js_value(key)?)
+            .map(|request| TypedStoreRequest::new(request, SerdeOptionMapper::<V>::map))
+    }
+
+    /// Retrieves the values of the records matching the given key or key range in query (up to limit if given).
+    pub fn get_all(
+        &self,
+        query: Option<Query>,
+        limit: Option<u32>,
+    ) -> Result<TypedStoreRequest<Vec<V>>, Error> {
+        self.inner
+            .get_all(query, limit)
+            .map(|request| TypedStoreRequest::new(request, SerdeVecMapper::<V>::map))
+    }
+
+    /// Retrieves the keys of records matching the given key or key range in query (up to limit if given).
+    pub fn get_all_keys(
+        &self,
+        query: Option<Query>,
+        limit: Option<u32>,
+    ) -> Result<TypedStoreRequest<Vec<K>>, Error> {
+        self.inner
+            .get_all_keys(query, limit)
+            .map(|request| TypedStoreRequest::new(request, SerdeVecMapper::<K>::map))
+    }
+
+    /// Retrieves the number of records matching the given key or key range in query.
+    pub fn count(&self, query: Option<Query>) -> Result<CountStoreRequest, Error> {
+        self.inner.count(query)
+    }
+
+    /// Returns a [`TypedIndex`] for the index named name in store. `IK` is the type of the index's keys.
+    pub fn index<IK>(&self, name: &str) -> Result<TypedIndex<IK, V>, Error>
+    where
+        IK: Serialize + DeserializeOwned + 'static,
+    {
+        self.inner.index(name).map(Into::into)
+    }
+}
+
+impl<K, V> From<ObjectStore> for TypedObjectStore<K, V> {
+    fn from(inner: ObjectStore) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V> From<TypedObjectStore<K, V>> for ObjectStore {
+    fn from(typed: TypedObjectStore<K, V>) -> Self {
+        typed.inner
+    }
+}
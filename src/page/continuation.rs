@@ -0,0 +1,177 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Error, Key};
+
+/// Maximum nesting depth of array keys accepted when parsing a token.
+const MAX_DEPTH: usize = 32;
+
+/// An opaque token for resuming a paginated query after the last record of a [`Page`](crate::Page).
+///
+/// The token encodes the key and the primary key of the last record and can be converted to and from a string (for
+/// example, to store it in a URL) using its [`Display`](fmt::Display) and [`FromStr`] implementations. The string only
+/// contains ASCII lowercase letters, digits and `:`, so it does not need to be escaped.
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Continuation {
+    key: Key,
+    primary_key: Key,
+}
+
+impl Continuation {
+    /// Creates a new [`Continuation`] resuming after the record with the given key and primary key.
+    pub fn new(key: Key, primary_key: Key) -> Self {
+        Self { key, primary_key }
+    }
+
+    /// Returns the key of the last record of the page.
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Returns the primary key of the last record of the page.
+    pub fn primary_key(&self) -> &Key {
+        &self.primary_key
+    }
+}
+
+impl fmt::Display for Continuation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut token = String::new();
+        encode_key(&self.key, &mut token);
+        encode_key(&self.primary_key, &mut token);
+
+        f.write_str(&token)
+    }
+}
+
+impl FromStr for Continuation {
+    type Err = Error;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        let (key, rest) = decode_key(token, 0).ok_or(Error::InvalidContinuation)?;
+        let (primary_key, rest) = decode_key(rest, 0).ok_or(Error::InvalidContinuation)?;
+
+        if !rest.is_empty() {
+            return Err(Error::InvalidContinuation);
+        }
+
+        Ok(Self::new(key, primary_key))
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+impl serde::Serialize for Continuation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Continuation {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let token = <String as serde::Deserialize>::deserialize(deserializer)?;
+        token.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Encodes a key as a type tag followed by its contents: the bits of numbers and dates in hex, the length and hex
+/// UTF-8 bytes of strings, the length and hex bytes of binary keys and the length and encoded items of arrays.
+fn encode_key(key: &Key, token: &mut String) {
+    match key {
+        Key::Number(number) => token.push_str(&format!("n{:016x}", number.to_bits())),
+        Key::Date(millis) => token.push_str(&format!("d{:016x}", millis.to_bits())),
+        Key::String(string) => {
+            token.push_str(&format!("s{}:", string.len()));
+            encode_bytes(string.as_bytes(), token);
+        }
+        Key::Binary(bytes) => {
+            token.push_str(&format!("b{}:", bytes.len()));
+            encode_bytes(bytes, token);
+        }
+        Key::Array(keys) => {
+            token.push_str(&format!("a{}:", keys.len()));
+
+            for key in keys {
+                encode_key(key, token);
+            }
+        }
+    }
+}
+
+/// Encodes bytes in hex.
+fn encode_bytes(bytes: &[u8], token: &mut String) {
+    for byte in bytes {
+        token.push_str(&format!("{byte:02x}"));
+    }
+}
+
+/// Decodes a key from the start of the token and returns it along with the rest of the token. `depth` is the number
+/// of arrays the key is nested in.
+fn decode_key(token: &str, depth: usize) -> Option<(Key, &str)> {
+    let tag = token.chars().next()?;
+    let rest = &token[tag.len_utf8()..];
+
+    match tag {
+        'n' | 'd' => {
+            let bits = u64::from_str_radix(rest.get(..16)?, 16).ok()?;
+            let number = f64::from_bits(bits);
+
+            let key = if tag == 'n' {
+                Key::Number(number)
+            } else {
+                Key::Date(number)
+            };
+
+            key.is_valid().then_some((key, &rest[16..]))
+        }
+        's' => {
+            let (bytes, rest) = decode_bytes(rest)?;
+            let string = String::from_utf8(bytes).ok()?;
+
+            Some((Key::String(string), rest))
+        }
+        'b' => {
+            let (bytes, rest) = decode_bytes(rest)?;
+
+            Some((Key::Binary(bytes), rest))
+        }
+        'a' if depth < MAX_DEPTH => {
+            let (length, mut rest) = decode_length(rest)?;
+            let mut keys = Vec::new();
+
+            for _ in 0..length {
+                let (key, remaining) = decode_key(rest, depth + 1)?;
+                keys.push(key);
+                rest = remaining;
+            }
+
+            Some((Key::Array(keys), rest))
+        }
+        _ => None,
+    }
+}
+
+/// Decodes a length followed by as many bytes in hex and returns them along with the rest of the token.
+fn decode_bytes(token: &str) -> Option<(Vec<u8>, &str)> {
+    let (length, rest) = decode_length(token)?;
+    let hex = rest.get(..length.checked_mul(2)?)?;
+
+    let bytes = (0..length)
+        .map(|i| u8::from_str_radix(hex.get(2 * i..2 * i + 2)?, 16).ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((bytes, &rest[hex.len()..]))
+}
+
+/// Decodes a decimal length followed by `:`.
+fn decode_length(token: &str) -> Option<(usize, &str)> {
+    let (length, rest) = token.split_once(':')?;
+
+    if length.is_empty() || !length.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((length.parse().ok()?, rest))
+}
@@ -0,0 +1,128 @@
+mod continuation;
+
+pub use self::continuation::Continuation;
+
+use std::cmp::Ordering;
+
+use wasm_bindgen::JsValue;
+
+use crate::{Cursor, CursorDirection, Error, Key, ManagedCursor, Record};
+
+/// A page of records returned by [`ObjectStore::page`](crate::ObjectStore::page) or
+/// [`Index::page`](crate::Index::page).
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page {
+    /// Records of the page
+    pub records: Vec<Record>,
+    /// Token for fetching the next page, or `None` if there are no more records
+    pub continuation: Option<Continuation>,
+}
+
+/// Reads a page of records from a cursor opened with the given direction, resuming after the given continuation.
+pub(crate) async fn read_page(
+    cursor: Option<Cursor>,
+    from_index: bool,
+    direction: CursorDirection,
+    page_size: u32,
+    after: Option<&Continuation>,
+) -> Result<Page, Error> {
+    let mut cursor = match cursor {
+        Some(cursor) if page_size > 0 => cursor.into_managed(),
+        _ => {
+            return Ok(Page {
+                records: Vec::new(),
+                continuation: None,
+            })
+        }
+    };
+
+    if let Some(after) = after {
+        resume(&mut cursor, from_index, direction, after).await?;
+    }
+
+    let mut records = Vec::new();
+    let mut continuation = None;
+
+    while let Some((key, primary_key)) = position(&cursor)? {
+        if records.len() == page_size as usize {
+            continuation = records.last().map(|record: &Record| {
+                Continuation::new(record.key.clone(), record.primary_key.clone())
+            });
+            break;
+        }
+
+        records.push(Record {
+            key,
+            primary_key,
+            value: cursor.value()?.unwrap_or(JsValue::UNDEFINED),
+        });
+
+        cursor.next(None).await?;
+    }
+
+    Ok(Page {
+        records,
+        continuation,
+    })
+}
+
+/// Moves the cursor to the first record after the continuation.
+async fn resume(
+    cursor: &mut ManagedCursor,
+    from_index: bool,
+    direction: CursorDirection,
+    after: &Continuation,
+) -> Result<(), Error> {
+    // Unique cursors visit every key once and object store keys are primary keys, so only keys need to be compared
+    let by_key = !from_index
+        || matches!(
+            direction,
+            CursorDirection::NextUnique | CursorDirection::PrevUnique
+        );
+    let reverse = matches!(
+        direction,
+        CursorDirection::Prev | CursorDirection::PrevUnique
+    );
+
+    let compare = |key: &Key, primary_key: &Key| {
+        let ordering = if by_key {
+            key.cmp(after.key())
+        } else {
+            (key, primary_key).cmp(&(after.key(), after.primary_key()))
+        };
+
+        if reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    };
+
+    let Some((key, primary_key)) = position(cursor)? else {
+        return Ok(());
+    };
+
+    match compare(&key, &primary_key) {
+        Ordering::Greater => return Ok(()),
+        Ordering::Equal => return cursor.next(None).await,
+        Ordering::Less if by_key => cursor.next(Some(&after.key().into())).await?,
+        Ordering::Less => {
+            cursor
+                .next_primary_key(&after.key().into(), &after.primary_key().into())
+                .await?
+        }
+    }
+
+    match position(cursor)? {
+        Some((key, primary_key)) if compare(&key, &primary_key) == Ordering::Equal => {
+            cursor.next(None).await
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the key and primary key of the record pointed at by the cursor, or `None` if it is finished.
+fn position(cursor: &ManagedCursor) -> Result<Option<(Key, Key)>, Error> {
+    Ok(cursor.key()?.zip(cursor.primary_key()?))
+}
@@ -1,6 +1,6 @@
 use wasm_bindgen::JsValue;
 
-use crate::KeyRange;
+use crate::{Key, KeyRange};
 
 /// Specifies a query when fetching data from object store
 #[derive(Debug, Clone, PartialEq)]
@@ -17,6 +17,12 @@ impl From<JsValue> for Query {
     }
 }
 
+impl From<Key> for Query {
+    fn from(value: Key) -> Self {
+        Query::Key(value.into())
+    }
+}
+
 impl From<KeyRange> for Query {
     fn from(value: KeyRange) -> Self {
         Query::KeyRange(value)
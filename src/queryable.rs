@@ -0,0 +1,399 @@
+#[cfg(feature = "futures")]
+use std::future::Future;
+
+#[cfg(feature = "futures")]
+use wasm_bindgen::JsValue;
+
+use crate::{
+    request::{
+        CountStoreRequest, GetAllKeysStoreRequest, GetAllStoreRequest, GetKeyStoreRequest,
+        GetStoreRequest, OpenCursorStoreRequest, OpenKeyCursorStoreRequest,
+    },
+    CursorDirection, Error, Query,
+};
+#[cfg(feature = "futures")]
+use crate::{Continuation, GetAllOptions, Page, Record};
+
+/// Read operations shared by [`ObjectStore`](crate::ObjectStore) and [`Index`](crate::Index), for writing code that
+/// queries either of them.
+///
+/// ```rust
+/// use idb::{Error, Query, Queryable};
+///
+/// fn count_matching<S: Queryable>(source: &S, query: Query) -> Result<(), Error> {
+///     let _request = source.count(Some(query))?;
+///     Ok(())
+/// }
+/// ```
+///
+/// The methods are also available on [`ObjectStore`](crate::ObjectStore) and [`Index`](crate::Index) without
+/// importing this trait.
+pub trait Queryable {
+    /// Returns the name of the object store or index.
+    fn name(&self) -> String;
+
+    /// Retrieves the value of the first record matching the given key or key range in query.
+    fn get(&self, query: impl Into<Query>) -> Result<GetStoreRequest, Error>;
+
+    /// Retrieves the key of the first record matching the given key or key range in query.
+    fn get_key(&self, query: impl Into<Query>) -> Result<GetKeyStoreRequest, Error>;
+
+    /// Retrieves the values of the records matching the given key or key range in query (up to limit if given).
+    fn get_all(
+        &self,
+        query: Option<Query>,
+        limit: Option<u32>,
+    ) -> Result<GetAllStoreRequest, Error>;
+
+    /// Retrieves the values of the records matching the given options.
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    fn get_all_with_options(
+        &self,
+        options: GetAllOptions,
+    ) -> impl Future<Output = Result<Vec<JsValue>, Error>>;
+
+    /// Retrieves the keys of records matching the given key or key range in query (up to limit if given).
+    fn get_all_keys(
+        &self,
+        query: Option<Query>,
+        limit: Option<u32>,
+    ) -> Result<GetAllKeysStoreRequest, Error>;
+
+    /// Retrieves the primary keys of the records matching the given options.
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    fn get_all_keys_with_options(
+        &self,
+        options: GetAllOptions,
+    ) -> impl Future<Output = Result<Vec<JsValue>, Error>>;
+
+    /// Retrieves the records (keys, primary keys and values) matching the given options.
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    fn get_all_records(
+        &self,
+        options: GetAllOptions,
+    ) -> impl Future<Output = Result<Vec<Record>, Error>>;
+
+    /// Retrieves the number of records matching the given key or key range in query.
+    fn count(&self, query: Option<Query>) -> Result<CountStoreRequest, Error>;
+
+    /// Opens a [`Cursor`](crate::Cursor) over the records matching query, ordered by direction.
+    fn open_cursor(
+        &self,
+        query: Option<Query>,
+        cursor_direction: Option<CursorDirection>,
+    ) -> Result<OpenCursorStoreRequest, Error>;
+
+    /// Opens a [`KeyCursor`](crate::KeyCursor) over the records matching query, ordered by direction.
+    fn open_key_cursor(
+        &self,
+        query: Option<Query>,
+        cursor_direction: Option<CursorDirection>,
+    ) -> Result<OpenKeyCursorStoreRequest, Error>;
+
+    /// Returns a page of (up to `page_size`) records matching query, ordered by direction, starting after the record
+    /// of the given [`Continuation`] (or from the start if `None`).
+    #[cfg(feature = "futures")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+    fn page(
+        &self,
+        query: Option<Query>,
+        cursor_direction: Option<CursorDirection>,
+        page_size: u32,
+        after: Option<&Continuation>,
+    ) -> impl Future<Output = Result<Page, Error>>;
+}
+
+/// Implements the read operations of [`Queryable`] for a type wrapping an `IdbObjectStore` or an `IdbIndex` (which
+/// have the same methods) in its `inner` field, both as inherent methods and as an implementation of the trait.
+macro_rules! impl_queryable {
+    ($type: ident, $source: literal, $from_index: literal) => {
+        impl $type {
+            /// Retrieves the value of the first record matching the given key or key range in query.
+            pub fn get(
+                &self,
+                query: impl Into<crate::Query>,
+            ) -> Result<crate::request::GetStoreRequest, crate::Error> {
+                self.inner
+                    .get(&query.into().into())
+                    .map(Into::into)
+                    .map_err(crate::Error::GetFailed)
+            }
+
+            /// Retrieves the key of the first record matching the given key or key range in query.
+            pub fn get_key(
+                &self,
+                query: impl Into<crate::Query>,
+            ) -> Result<crate::request::GetKeyStoreRequest, crate::Error> {
+                self.inner
+                    .get_key(&query.into().into())
+                    .map(Into::into)
+                    .map_err(crate::Error::GetKeyFailed)
+            }
+
+            /// Retrieves the values of the records matching the given key or key range in query (up to limit if
+            /// given).
+            pub fn get_all(
+                &self,
+                query: Option<crate::Query>,
+                limit: Option<u32>,
+            ) -> Result<crate::request::GetAllStoreRequest, crate::Error> {
+                self.inner
+                    .get_all_with_key_and_limit(
+                        &query.map_or(wasm_bindgen::JsValue::NULL, Into::into),
+                        limit.unwrap_or_default(),
+                    )
+                    .map(Into::into)
+                    .map_err(crate::Error::GetAllFailed)
+            }
+
+            /// Retrieves the values of the records matching the given options. Records in other than forward
+            /// ([`CursorDirection::Next`](crate::CursorDirection::Next)) order are retrieved natively where the
+            /// browser supports it and using a cursor otherwise.
+            #[cfg(feature = "futures")]
+            #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+            pub async fn get_all_with_options(
+                &self,
+                options: crate::GetAllOptions,
+            ) -> Result<Vec<wasm_bindgen::JsValue>, crate::Error> {
+                if options.is_forward() {
+                    return self.get_all(options.query, options.limit)?.await;
+                }
+
+                let records = self.get_all_records(options).await?;
+                Ok(records.into_iter().map(|record| record.value).collect())
+            }
+
+            /// Retrieves the keys of records matching the given key or key range in query (up to limit if given).
+            pub fn get_all_keys(
+                &self,
+                query: Option<crate::Query>,
+                limit: Option<u32>,
+            ) -> Result<crate::request::GetAllKeysStoreRequest, crate::Error> {
+                self.inner
+                    .get_all_keys_with_key_and_limit(
+                        &query.map_or(wasm_bindgen::JsValue::NULL, Into::into),
+                        limit.unwrap_or_default(),
+                    )
+                    .map(Into::into)
+                    .map_err(crate::Error::GetAllKeysFailed)
+            }
+
+            /// Retrieves the primary keys of the records matching the given options. Keys in other than forward
+            /// ([`CursorDirection::Next`](crate::CursorDirection::Next)) order are retrieved natively where the
+            /// browser supports it and using a key cursor otherwise.
+            #[cfg(feature = "futures")]
+            #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+            pub async fn get_all_keys_with_options(
+                &self,
+                options: crate::GetAllOptions,
+            ) -> Result<Vec<wasm_bindgen::JsValue>, crate::Error> {
+                if options.is_forward() {
+                    return self.get_all_keys(options.query, options.limit)?.await;
+                }
+
+                crate::record::get_all_primary_keys(
+                    self.inner.as_ref(),
+                    options,
+                    |query, cursor_direction| self.open_key_cursor(query, cursor_direction),
+                )
+                .await
+            }
+
+            /// Retrieves the records (keys, primary keys and values) matching the given options in a single pass.
+            /// Uses `getAllRecords()` where the browser supports it and falls back to reading the records with a
+            /// cursor otherwise.
+            #[cfg(feature = "futures")]
+            #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+            pub async fn get_all_records(
+                &self,
+                options: crate::GetAllOptions,
+            ) -> Result<Vec<crate::Record>, crate::Error> {
+                crate::record::get_all_records(
+                    self.inner.as_ref(),
+                    options,
+                    |query, cursor_direction| self.open_cursor(query, cursor_direction),
+                )
+                .await
+            }
+
+            /// Retrieves the number of records matching the given key or key range in query.
+            pub fn count(
+                &self,
+                query: Option<crate::Query>,
+            ) -> Result<crate::request::CountStoreRequest, crate::Error> {
+                match query {
+                    None => self
+                        .inner
+                        .count()
+                        .map(Into::into)
+                        .map_err(crate::Error::CountFailed),
+                    Some(query) => self
+                        .inner
+                        .count_with_key(&query.into())
+                        .map(Into::into)
+                        .map_err(crate::Error::CountFailed),
+                }
+            }
+
+            #[doc = concat!(
+                "Opens a [`Cursor`](crate::Cursor) over the records matching query, ordered by direction. If query ",
+                "is `None`, all records in ",
+                $source,
+                " are matched."
+            )]
+            pub fn open_cursor(
+                &self,
+                query: Option<crate::Query>,
+                cursor_direction: Option<crate::CursorDirection>,
+            ) -> Result<crate::request::OpenCursorStoreRequest, crate::Error> {
+                self.inner
+                    .open_cursor_with_range_and_direction(
+                        &query.map_or(wasm_bindgen::JsValue::NULL, Into::into),
+                        cursor_direction.unwrap_or_default().into(),
+                    )
+                    .map(Into::into)
+                    .map_err(crate::Error::OpenCursorFailed)
+            }
+
+            #[doc = concat!(
+                "Opens a [`KeyCursor`](crate::KeyCursor) over the records matching query, ordered by direction. If ",
+                "query is `None`, all records in ",
+                $source,
+                " are matched."
+            )]
+            pub fn open_key_cursor(
+                &self,
+                query: Option<crate::Query>,
+                cursor_direction: Option<crate::CursorDirection>,
+            ) -> Result<crate::request::OpenKeyCursorStoreRequest, crate::Error> {
+                self.inner
+                    .open_key_cursor_with_range_and_direction(
+                        &query.map_or(wasm_bindgen::JsValue::NULL, Into::into),
+                        cursor_direction.unwrap_or_default().into(),
+                    )
+                    .map(Into::into)
+                    .map_err(crate::Error::OpenCursorFailed)
+            }
+
+            /// Returns a page of (up to `page_size`) records matching query, ordered by direction, starting after
+            /// the record of the given [`Continuation`](crate::Continuation) (or from the start if `None`). The
+            /// returned [`Page`](crate::Page) contains a continuation for fetching the next page if there are more
+            /// records.
+            #[cfg(feature = "futures")]
+            #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+            pub async fn page(
+                &self,
+                query: Option<crate::Query>,
+                cursor_direction: Option<crate::CursorDirection>,
+                page_size: u32,
+                after: Option<&crate::Continuation>,
+            ) -> Result<crate::Page, crate::Error> {
+                let cursor_direction = cursor_direction.unwrap_or_default();
+                let cursor = self.open_cursor(query, Some(cursor_direction))?.await?;
+
+                crate::page::read_page(cursor, $from_index, cursor_direction, page_size, after).await
+            }
+        }
+
+        impl crate::Queryable for $type {
+            fn name(&self) -> String {
+                <$type>::name(self)
+            }
+
+            fn get(
+                &self,
+                query: impl Into<crate::Query>,
+            ) -> Result<crate::request::GetStoreRequest, crate::Error> {
+                <$type>::get(self, query)
+            }
+
+            fn get_key(
+                &self,
+                query: impl Into<crate::Query>,
+            ) -> Result<crate::request::GetKeyStoreRequest, crate::Error> {
+                <$type>::get_key(self, query)
+            }
+
+            fn get_all(
+                &self,
+                query: Option<crate::Query>,
+                limit: Option<u32>,
+            ) -> Result<crate::request::GetAllStoreRequest, crate::Error> {
+                <$type>::get_all(self, query, limit)
+            }
+
+            #[cfg(feature = "futures")]
+            fn get_all_with_options(
+                &self,
+                options: crate::GetAllOptions,
+            ) -> impl std::future::Future<Output = Result<Vec<wasm_bindgen::JsValue>, crate::Error>>
+            {
+                <$type>::get_all_with_options(self, options)
+            }
+
+            fn get_all_keys(
+                &self,
+                query: Option<crate::Query>,
+                limit: Option<u32>,
+            ) -> Result<crate::request::GetAllKeysStoreRequest, crate::Error> {
+                <$type>::get_all_keys(self, query, limit)
+            }
+
+            #[cfg(feature = "futures")]
+            fn get_all_keys_with_options(
+                &self,
+                options: crate::GetAllOptions,
+            ) -> impl std::future::Future<Output = Result<Vec<wasm_bindgen::JsValue>, crate::Error>>
+            {
+                <$type>::get_all_keys_with_options(self, options)
+            }
+
+            #[cfg(feature = "futures")]
+            fn get_all_records(
+                &self,
+                options: crate::GetAllOptions,
+            ) -> impl std::future::Future<Output = Result<Vec<crate::Record>, crate::Error>> {
+                <$type>::get_all_records(self, options)
+            }
+
+            fn count(
+                &self,
+                query: Option<crate::Query>,
+            ) -> Result<crate::request::CountStoreRequest, crate::Error> {
+                <$type>::count(self, query)
+            }
+
+            fn open_cursor(
+                &self,
+                query: Option<crate::Query>,
+                cursor_direction: Option<crate::CursorDirection>,
+            ) -> Result<crate::request::OpenCursorStoreRequest, crate::Error> {
+                <$type>::open_cursor(self, query, cursor_direction)
+            }
+
+            fn open_key_cursor(
+                &self,
+                query: Option<crate::Query>,
+                cursor_direction: Option<crate::CursorDirection>,
+            ) -> Result<crate::request::OpenKeyCursorStoreRequest, crate::Error> {
+                <$type>::open_key_cursor(self, query, cursor_direction)
+            }
+
+            #[cfg(feature = "futures")]
+            fn page(
+                &self,
+                query: Option<crate::Query>,
+                cursor_direction: Option<crate::CursorDirection>,
+                page_size: u32,
+                after: Option<&crate::Continuation>,
+            ) -> impl std::future::Future<Output = Result<crate::Page, crate::Error>> {
+                <$type>::page(self, query, cursor_direction, page_size, after)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_queryable;
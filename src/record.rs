@@ -0,0 +1,147 @@
+#[cfg(feature = "futures")]
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+
+use crate::Key;
+#[cfg(feature = "futures")]
+use crate::{
+    request::{
+        GetAllKeysStoreRequest, GetAllStoreRequest, OpenCursorStoreRequest,
+        OpenKeyCursorStoreRequest,
+    },
+    CursorDirection, Error, GetAllOptions, Query,
+};
+
+/// A record of an object store, as seen from an object store or an index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// Key of the record in its source: the index key for indexes and the primary key for object stores
+    pub key: Key,
+    /// Primary key of the record
+    pub primary_key: Key,
+    /// Value of the record
+    pub value: JsValue,
+}
+
+#[cfg(feature = "futures")]
+impl Record {
+    /// Converts an `IDBRecord` returned by `getAllRecords()` into a [`Record`].
+    fn from_js_value(record: &JsValue) -> Result<Self, Error> {
+        let get = |name: &str| {
+            js_sys::Reflect::get(record, &JsValue::from_str(name)).map_err(Error::GetAllFailed)
+        };
+
+        Ok(Self {
+            key: get("key")?.try_into()?,
+            primary_key: get("primaryKey")?.try_into()?,
+            value: get("value")?,
+        })
+    }
+}
+
+/// Retrieves the records matching the options from an object store or an index. Uses `getAllRecords()` if the browser
+/// supports it and falls back to reading the records with a cursor otherwise.
+#[cfg(feature = "futures")]
+pub(crate) async fn get_all_records(
+    source: &JsValue,
+    options: GetAllOptions,
+    open_cursor: impl FnOnce(
+        Option<Query>,
+        Option<CursorDirection>,
+    ) -> Result<OpenCursorStoreRequest, Error>,
+) -> Result<Vec<Record>, Error> {
+    if supports_get_all_options(source)? {
+        return GetAllStoreRequest::from(call_with_options(source, "getAllRecords", &options)?)
+            .await?
+            .iter()
+            .map(Record::from_js_value)
+            .collect();
+    }
+
+    let limit = options.limit();
+    let mut records = Vec::new();
+
+    let Some(cursor) = open_cursor(options.query, options.direction)?.await? else {
+        return Ok(records);
+    };
+    let mut cursor = cursor.into_managed();
+
+    while let (Some(key), Some(primary_key)) = (cursor.key()?, cursor.primary_key()?) {
+        if limit == Some(records.len()) {
+            break;
+        }
+
+        records.push(Record {
+            key,
+            primary_key,
+            value: cursor.value()?.unwrap_or(JsValue::UNDEFINED),
+        });
+
+        cursor.next(None).await?;
+    }
+
+    Ok(records)
+}
+
+/// Retrieves the primary keys of the records matching the options from an object store or an index. Uses
+/// `getAllKeys()` with the options if the browser supports them (along with `getAllRecords()`) and falls back to
+/// reading the keys with a key cursor otherwise.
+#[cfg(feature = "futures")]
+pub(crate) async fn get_all_primary_keys(
+    source: &JsValue,
+    options: GetAllOptions,
+    open_key_cursor: impl FnOnce(
+        Option<Query>,
+        Option<CursorDirection>,
+    ) -> Result<OpenKeyCursorStoreRequest, Error>,
+) -> Result<Vec<JsValue>, Error> {
+    if supports_get_all_options(source)? {
+        return GetAllKeysStoreRequest::from(call_with_options(source, "getAllKeys", &options)?)
+            .await;
+    }
+
+    let limit = options.limit();
+    let mut primary_keys = Vec::new();
+
+    let Some(cursor) = open_key_cursor(options.query, options.direction)?.await? else {
+        return Ok(primary_keys);
+    };
+    let mut cursor = cursor.into_managed();
+
+    while let Some(primary_key) = cursor.primary_key()? {
+        if limit == Some(primary_keys.len()) {
+            break;
+        }
+
+        primary_keys.push(primary_key.into());
+
+        cursor.next(None).await?;
+    }
+
+    Ok(primary_keys)
+}
+
+/// Returns `true` if the source accepts `IDBGetAllOptions`, which browsers support along with `getAllRecords()`.
+#[cfg(feature = "futures")]
+fn supports_get_all_options(source: &JsValue) -> Result<bool, Error> {
+    js_sys::Reflect::get(source, &JsValue::from_str("getAllRecords"))
+        .map(|function| function.is_function())
+        .map_err(Error::GetAllFailed)
+}
+
+/// Calls the method of the source with the given name, passing the options as an `IDBGetAllOptions` dictionary.
+#[cfg(feature = "futures")]
+fn call_with_options(
+    source: &JsValue,
+    method: &str,
+    options: &GetAllOptions,
+) -> Result<web_sys::IdbRequest, Error> {
+    let options = options.to_js_value()?;
+
+    js_sys::Reflect::get(source, &JsValue::from_str(method))
+        .and_then(|function| function.dyn_into::<js_sys::Function>())
+        .and_then(|function| function.call1(source, &options))
+        .map_err(Error::GetAllFailed)?
+        .dyn_into()
+        .map_err(|value| Error::UnexpectedJsType("IdbRequest", value))
+}
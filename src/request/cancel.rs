@@ -0,0 +1,87 @@
+use std::{
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
+    task::Poll,
+    time::Duration,
+};
+
+use tokio::sync::oneshot;
+use web_sys::AbortSignal;
+
+use crate::{utils::Timeout, Error, EventListener, Transaction};
+
+/// Waits for the future of a request, aborting the transaction of the request and resolving with
+/// [`Error::TimedOut`] if it does not complete within the given duration.
+pub(crate) async fn with_timeout<F, T>(
+    future: F,
+    transaction: Option<Transaction>,
+    timeout: Duration,
+) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>,
+{
+    let (sender, receiver) = oneshot::channel();
+    let _timeout = Timeout::new(timeout, move || {
+        cancel(transaction, sender, Error::TimedOut)
+    })?;
+
+    race(future, receiver).await
+}
+
+/// Waits for the future of a request, aborting the transaction of the request and resolving with
+/// [`Error::Cancelled`] if the given signal is aborted before it completes.
+pub(crate) async fn with_signal<F, T>(
+    future: F,
+    transaction: Option<Transaction>,
+    signal: &AbortSignal,
+) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>,
+{
+    let (sender, receiver) = oneshot::channel();
+
+    if signal.aborted() {
+        cancel(transaction, sender, Error::Cancelled);
+        return race(future, receiver).await;
+    }
+
+    let mut cancellation = Some((transaction, sender));
+    let _listener = EventListener::new(signal, "abort", move |_| {
+        if let Some((transaction, sender)) = cancellation.take() {
+            cancel(transaction, sender, Error::Cancelled);
+        }
+    })?;
+
+    race(future, receiver).await
+}
+
+/// Aborts the transaction (if it is still running) and sends the error of the cancellation.
+fn cancel(transaction: Option<Transaction>, sender: oneshot::Sender<Error>, error: Error) {
+    if let Some(transaction) = transaction {
+        // The transaction may already be finished, in which case there is nothing to abort
+        let _ = transaction.abort();
+    }
+
+    let _ = sender.send(error);
+}
+
+/// Resolves with the result of the future, or with the error of the cancellation if it fires first. The cancellation
+/// is checked first because aborting the transaction also fails the request with an `AbortError`.
+async fn race<F, T>(future: F, mut cancelled: oneshot::Receiver<Error>) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>,
+{
+    let mut future = pin!(future);
+
+    poll_fn(|cx| {
+        if let Poll::Ready(error) = Pin::new(&mut cancelled).poll(cx) {
+            return match error {
+                Ok(error) => Poll::Ready(Err(error)),
+                Err(_) => Poll::Ready(Err(Error::OneshotChannelReceiveError)),
+            };
+        }
+
+        future.as_mut().poll(cx)
+    })
+    .await
+}
@@ -48,7 +48,7 @@ macro_rules! impl_database_request {
                 self.inner.error()
             }
 
-            fn source(&self) -> Result<js_sys::Object, crate::Error> {
+            fn source(&self) -> Result<crate::request::RequestSource, crate::Error> {
                 self.inner.source()
             }
 
@@ -4,13 +4,12 @@ mod macros;
 #[cfg(feature = "futures")]
 mod futures;
 
-use js_sys::Object;
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
 use web_sys::{DomException, Event, EventTarget, IdbOpenDbRequest, IdbVersionChangeEvent};
 
 use crate::{
     event::{DeleteDatabaseRequestEvent, OpenDatabaseRequestEvent, VersionChangeEvent},
-    request::RequestReadyState,
+    request::{RequestReadyState, RequestSource},
     Database, Error, Request, Transaction,
 };
 
@@ -106,8 +105,9 @@ impl Request for DatabaseRequest {
         self.inner.error().map_err(Error::RequestErrorNotFound)
     }
 
-    fn source(&self) -> Result<Object, Error> {
-        self.inner.source().ok_or(Error::RequestSourceNotFound)
+    fn source(&self) -> Result<RequestSource, Error> {
+        let source = self.inner.source().ok_or(Error::RequestSourceNotFound)?;
+        JsValue::from(source).try_into()
     }
 
     fn transaction(&self) -> Option<Transaction> {
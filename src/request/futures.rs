@@ -1,4 +1,7 @@
 //! This module contains all the `Future` types for handling database requests (using `async`/`await` syntax).
+#[cfg(feature = "serde")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+pub use super::store::TypedStoreRequestFuture;
 pub use super::{
     database::{DeleteDatabaseRequestFuture, OpenDatabaseRequestFuture},
     store::{
@@ -1,14 +1,25 @@
 //! This module contains all the types for handling database requests.
+#[cfg(feature = "futures")]
+mod cancel;
 mod database;
 #[cfg(feature = "futures")]
 #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
 pub mod futures;
 mod request_ready_state;
+mod request_source;
 mod store;
+#[cfg(feature = "serde")]
+mod typed_store_request;
 
+#[cfg(feature = "futures")]
+pub(crate) use self::store::StoreRequest;
+#[cfg(feature = "serde")]
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+pub use self::typed_store_request::TypedStoreRequest;
 pub use self::{
     database::{DeleteDatabaseRequest, OpenDatabaseRequest},
     request_ready_state::RequestReadyState,
+    request_source::RequestSource,
     store::{
         AddStoreRequest, ClearStoreRequest, CountStoreRequest, DeleteStoreRequest,
         GetAllKeysStoreRequest, GetAllStoreRequest, GetKeyStoreRequest, GetStoreRequest,
@@ -16,7 +27,6 @@ pub use self::{
     },
 };
 
-use js_sys::Object;
 use wasm_bindgen::JsValue;
 use web_sys::DomException;
 
@@ -35,9 +45,9 @@ pub trait Request {
     /// an [`Error`] if the request is still pending.
     fn error(&self) -> Result<Option<DomException>, Error>;
 
-    /// Returns the `ObjectStore`, `Index`, or `Cursor` the request was made against, or `null` if it was an open
-    /// request.
-    fn source(&self) -> Result<Object, Error>; // TODO: make return type as enum: (IDBObjectStore or IDBIndex or IDBCursor)
+    /// Returns the `ObjectStore`, `Index`, or `Cursor` the request was made against. Returns an [`Error`] if it was an
+    /// open request (which has no source).
+    fn source(&self) -> Result<RequestSource, Error>;
 
     /// Returns the `Transaction` the request was made within. If this as an open request, then it returns an upgrade
     /// transaction while it is running, or `None` otherwise.
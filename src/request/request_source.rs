@@ -0,0 +1,73 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{IdbCursor, IdbCursorWithValue, IdbIndex, IdbObjectStore};
+
+use crate::{Cursor, Error, Index, KeyCursor, ObjectStore};
+
+/// The [`ObjectStore`], [`Index`] or cursor a request was made against, returned by
+/// [`Request::source`](crate::Request::source).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestSource {
+    /// The request was made against an object store
+    ObjectStore(ObjectStore),
+    /// The request was made against an index
+    Index(Index),
+    /// The request was made against a cursor, to update or delete its current record (requests for advancing a
+    /// cursor keep the object store or index the cursor was opened from as their source)
+    Cursor(Cursor),
+    /// The request was made against a key cursor (browsers do not allow updating or deleting records through key
+    /// cursors, so requests are not expected to have this source)
+    KeyCursor(KeyCursor),
+}
+
+impl RequestSource {
+    /// Returns the object store the request was made against, directly or through an index or a cursor.
+    pub fn object_store(&self) -> Result<ObjectStore, Error> {
+        match self {
+            RequestSource::ObjectStore(object_store) => Ok(object_store.clone()),
+            RequestSource::Index(index) => Ok(index.object_store()),
+            RequestSource::Cursor(cursor) => cursor.source().map(|source| source.object_store()),
+            RequestSource::KeyCursor(cursor) => cursor.source().map(|source| source.object_store()),
+        }
+    }
+}
+
+impl TryFrom<JsValue> for RequestSource {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        if value.is_instance_of::<IdbObjectStore>() {
+            Ok(RequestSource::ObjectStore(
+                value.unchecked_into::<IdbObjectStore>().into(),
+            ))
+        } else if value.is_instance_of::<IdbIndex>() {
+            Ok(RequestSource::Index(
+                value.unchecked_into::<IdbIndex>().into(),
+            ))
+        } else if value.is_instance_of::<IdbCursorWithValue>() {
+            // Cursors with values are also cursors, so they are checked first
+            Ok(RequestSource::Cursor(
+                value.unchecked_into::<IdbCursorWithValue>().into(),
+            ))
+        } else if value.is_instance_of::<IdbCursor>() {
+            Ok(RequestSource::KeyCursor(
+                value.unchecked_into::<IdbCursor>().into(),
+            ))
+        } else {
+            Err(Error::UnexpectedJsType(
+                "IdbObjectStore, IdbIndex or IdbCursor",
+                value,
+            ))
+        }
+    }
+}
+
+impl From<RequestSource> for JsValue {
+    fn from(source: RequestSource) -> Self {
+        match source {
+            RequestSource::ObjectStore(object_store) => object_store.into(),
+            RequestSource::Index(index) => index.into(),
+            RequestSource::Cursor(cursor) => cursor.into(),
+            RequestSource::KeyCursor(cursor) => cursor.into(),
+        }
+    }
+}
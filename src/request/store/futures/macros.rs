@@ -1,42 +1,101 @@
+/// Implements `IntoFuture` for a store request.
+///
+/// By default, the future resolves with the result of the request's [`StoreEvent`](crate::StoreEvent). Requests whose
+/// result is mapped at runtime provide a type parameter, a function returning the `success` callback for a request and
+/// the `error` callback.
 macro_rules! impl_store_request_future {
     ($type: ident, $request: ty, $return_type: ty, $doc: expr) => {
+        impl_store_request_future!(
+            $type[],
+            $request,
+            $return_type,
+            |_: &$request| {
+                |event: <$request as crate::Request>::Event| crate::StoreEvent::result(&event)
+            },
+            |event: <$request as crate::Request>::Event| crate::StoreEvent::error(&event),
+            $doc
+        );
+    };
+    ($type: ident [$($generic: ident)?], $request: ty, $return_type: ty, $success: expr, $error: expr, $doc: expr) => {
         #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
         #[doc = $doc]
-        pub struct $type {
-            _inner: $request,
+        pub struct $type$(<$generic>)? {
+            inner: $request,
             success_receiver: tokio::sync::oneshot::Receiver<Result<$return_type, Error>>,
             error_receiver: tokio::sync::oneshot::Receiver<Error>,
         }
 
         #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
-        impl std::future::IntoFuture for $request {
+        impl$(<$generic: 'static>)? std::future::IntoFuture for $request {
             type Output = <Self::IntoFuture as std::future::Future>::Output;
 
-            type IntoFuture = $type;
+            type IntoFuture = $type$(<$generic>)?;
 
             fn into_future(mut self) -> Self::IntoFuture {
                 let (error_sender, error_receiver) = tokio::sync::oneshot::channel();
                 let (success_sender, success_receiver) = tokio::sync::oneshot::channel();
 
+                let success_callback = ($success)(&self);
+                let error_callback = $error;
+
                 crate::Request::on_error(&mut self, move |event| {
-                    let result = crate::StoreEvent::error(&event);
+                    let result = error_callback(event);
                     let _ = error_sender.send(result);
                 });
 
                 crate::Request::on_success(&mut self, move |event| {
-                    let result = crate::StoreEvent::result(&event);
+                    let result = success_callback(event);
                     let _ = success_sender.send(result);
                 });
 
                 $type {
-                    _inner: self,
+                    inner: self,
                     success_receiver,
                     error_receiver,
                 }
             }
         }
 
-        impl std::future::Future for $type {
+        #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "futures")))]
+        impl$(<$generic: 'static>)? $request {
+            /// Waits for the request to complete. If it does not complete within the given duration, aborts the
+            /// transaction of the request and returns [`Error::TimedOut`](crate::Error::TimedOut).
+            pub async fn await_with_timeout(
+                self,
+                timeout: std::time::Duration,
+            ) -> Result<$return_type, crate::Error> {
+                let transaction = crate::Request::transaction(&self);
+                crate::request::cancel::with_timeout(
+                    std::future::IntoFuture::into_future(self),
+                    transaction,
+                    timeout,
+                )
+                .await
+            }
+
+            /// Waits for the request to complete. If the signal is aborted before it completes, aborts the
+            /// transaction of the request and returns [`Error::Cancelled`](crate::Error::Cancelled).
+            pub async fn await_with_signal(
+                self,
+                signal: &web_sys::AbortSignal,
+            ) -> Result<$return_type, crate::Error> {
+                let transaction = crate::Request::transaction(&self);
+                crate::request::cancel::with_signal(
+                    std::future::IntoFuture::into_future(self),
+                    transaction,
+                    signal,
+                )
+                .await
+            }
+        }
+
+        impl$(<$generic>)? Drop for $type$(<$generic>)? {
+            fn drop(&mut self) {
+                self.inner.clear_callbacks();
+            }
+        }
+
+        impl$(<$generic>)? std::future::Future for $type$(<$generic>)? {
             type Output = Result<$return_type, crate::Error>;
 
             fn poll(
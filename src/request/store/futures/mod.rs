@@ -12,8 +12,12 @@ mod get_store_request;
 mod open_cursor_store_request;
 mod open_key_cursor_store_request;
 mod put_store_request;
+#[cfg(feature = "serde")]
+mod typed_store_request;
 mod update_store_request;
 
+#[cfg(feature = "serde")]
+pub use self::typed_store_request::TypedStoreRequestFuture;
 pub use self::{
     add_store_request::AddStoreRequestFuture, clear_store_request::ClearStoreRequestFuture,
     count_store_request::CountStoreRequestFuture, delete_store_request::DeleteStoreRequestFuture,
@@ -0,0 +1,10 @@
+use crate::Error;
+
+impl_store_request_future!(
+    TypedStoreRequestFuture[T],
+    crate::request::TypedStoreRequest<T>,
+    T,
+    crate::request::TypedStoreRequest::success_callback,
+    crate::request::TypedStoreRequest::<T>::error_callback,
+    "Future returned by [`TypedStoreRequest::into_future`](crate::request::TypedStoreRequest::into_future)."
+);
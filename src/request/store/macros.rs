@@ -14,6 +14,12 @@ macro_rules! impl_store_request {
             pub fn forget_callbacks(&mut self) {
                 self.inner.forget_callbacks();
             }
+
+            /// Removes the callbacks from the request and drops them.
+            #[cfg(feature = "futures")]
+            pub(crate) fn clear_callbacks(&mut self) {
+                self.inner.clear_callbacks();
+            }
         }
 
         impl crate::Request for $type {
@@ -27,7 +33,7 @@ macro_rules! impl_store_request {
                 self.inner.error()
             }
 
-            fn source(&self) -> Result<js_sys::Object, crate::Error> {
+            fn source(&self) -> Result<crate::request::RequestSource, crate::Error> {
                 self.inner.source()
             }
 
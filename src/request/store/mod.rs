@@ -4,6 +4,8 @@ mod macros;
 #[cfg(feature = "futures")]
 mod futures;
 
+#[cfg(feature = "serde")]
+pub use self::futures::TypedStoreRequestFuture;
 #[cfg(feature = "futures")]
 pub use self::futures::{
     AddStoreRequestFuture, ClearStoreRequestFuture, CountStoreRequestFuture,
@@ -12,11 +14,13 @@ pub use self::futures::{
     OpenKeyCursorStoreRequestFuture, PutStoreRequestFuture, UpdateStoreRequestFuture,
 };
 
-use js_sys::Object;
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
 use web_sys::{DomException, Event, EventTarget, IdbRequest};
 
-use crate::{request::RequestReadyState, Error, Request, Transaction};
+use crate::{
+    request::{RequestReadyState, RequestSource},
+    Error, Request, Transaction,
+};
 
 /// Request returned when performing operations on an [`ObjectStore`](crate::ObjectStore).
 #[derive(Debug)]
@@ -43,6 +47,19 @@ impl StoreRequest {
             callback.forget();
         }
     }
+
+    /// Removes the callbacks from the request and drops them, so that the request does not call dropped callbacks if
+    /// it completes later (for example, when it fails because its transaction was aborted).
+    #[cfg(feature = "futures")]
+    pub(crate) fn clear_callbacks(&mut self) {
+        if self.success_callback.take().is_some() {
+            self.inner.set_onsuccess(None);
+        }
+
+        if self.error_callback.take().is_some() {
+            self.inner.set_onerror(None);
+        }
+    }
 }
 
 impl Request for StoreRequest {
@@ -56,8 +73,9 @@ impl Request for StoreRequest {
         self.inner.error().map_err(Error::RequestErrorNotFound)
     }
 
-    fn source(&self) -> Result<Object, Error> {
-        self.inner.source().ok_or(Error::RequestSourceNotFound)
+    fn source(&self) -> Result<RequestSource, Error> {
+        let source = self.inner.source().ok_or(Error::RequestSourceNotFound)?;
+        JsValue::from(source).try_into()
     }
 
     fn transaction(&self) -> Option<Transaction> {
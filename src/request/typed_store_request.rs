@@ -0,0 +1,104 @@
+use wasm_bindgen::JsValue;
+use web_sys::{DomException, Event};
+
+use crate::{
+    mappers::ErrorMapper,
+    request::{store::StoreRequest, RequestReadyState, RequestSource},
+    Error, Request, Transaction,
+};
+
+/// Request returned when performing operations on a [`TypedObjectStore`](crate::TypedObjectStore) or
+/// [`TypedIndex`](crate::TypedIndex). Resolves to a deserialized value of type `T`.
+#[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+#[derive(Debug)]
+pub struct TypedStoreRequest<T> {
+    inner: StoreRequest,
+    mapper: fn(Result<JsValue, Error>) -> Result<T, Error>,
+}
+
+impl<T> TypedStoreRequest<T> {
+    pub(crate) fn new(
+        inner: impl Into<StoreRequest>,
+        mapper: fn(Result<JsValue, Error>) -> Result<T, Error>,
+    ) -> Self {
+        Self {
+            inner: inner.into(),
+            mapper,
+        }
+    }
+
+    /// Release memory management of the callbacks to JS GC.
+    ///
+    /// > Note: This may leak memory. Read more about it
+    /// > [here](https://docs.rs/wasm-bindgen/latest/wasm_bindgen/closure/struct.Closure.html#method.into_js_value).
+    pub fn forget_callbacks(&mut self) {
+        self.inner.forget_callbacks();
+    }
+
+    /// Removes the callbacks from the request and drops them.
+    pub(crate) fn clear_callbacks(&mut self) {
+        self.inner.clear_callbacks();
+    }
+
+    /// Returns the `success` callback of the future of the request, which maps the result of the request.
+    pub(crate) fn success_callback(&self) -> impl FnOnce(Event) -> Result<T, Error> {
+        let mapper = self.mapper;
+
+        move |event| {
+            let target = crate::Event::target(&event)?;
+            let request = StoreRequest::try_from(target)?;
+            mapper(request.result())
+        }
+    }
+
+    /// Returns the `error` callback of the future of the request, which maps the error of the request.
+    pub(crate) fn error_callback(event: Event) -> Error {
+        let target = match crate::Event::target(&event) {
+            Ok(target) => target,
+            Err(err) => return err,
+        };
+
+        match StoreRequest::try_from(target) {
+            Ok(request) => ErrorMapper::map(request.error()),
+            Err(err) => err,
+        }
+    }
+}
+
+impl<T> Request for TypedStoreRequest<T> {
+    type Event = Event;
+
+    fn result(&self) -> Result<JsValue, Error> {
+        self.inner.result()
+    }
+
+    fn error(&self) -> Result<Option<DomException>, Error> {
+        self.inner.error()
+    }
+
+    fn source(&self) -> Result<RequestSource, Error> {
+        self.inner.source()
+    }
+
+    fn transaction(&self) -> Option<Transaction> {
+        self.inner.transaction()
+    }
+
+    fn ready_state(&self) -> Result<RequestReadyState, Error> {
+        self.inner.ready_state()
+    }
+
+    fn on_success<F>(&mut self, callback: F)
+    where
+        F: FnOnce(Self::Event) + 'static,
+    {
+        self.inner.on_success(callback);
+    }
+
+    fn on_error<F>(&mut self, callback: F)
+    where
+        F: FnOnce(Self::Event) + 'static,
+    {
+        self.inner.on_error(callback);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::KeyPath;
+
+/// Snapshot of the schema of a database, returned by [`Database::schema`](crate::Database::schema).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatabaseSchema {
+    /// Name of the database
+    pub name: String,
+    /// Version of the database
+    pub version: u32,
+    /// Object stores of the database, ordered by name
+    pub object_stores: Vec<ObjectStoreSchema>,
+}
+
+impl DatabaseSchema {
+    /// Returns the schema of the object store with the given name.
+    pub fn object_store(&self, name: &str) -> Option<&ObjectStoreSchema> {
+        self.object_stores
+            .iter()
+            .find(|object_store| object_store.name == name)
+    }
+}
+
+/// Snapshot of the schema of an object store, returned by [`ObjectStore::schema`](crate::ObjectStore::schema).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObjectStoreSchema {
+    /// Name of the object store
+    pub name: String,
+    /// Key path of the object store, or `None` if it uses out-of-line keys
+    pub key_path: Option<KeyPath>,
+    /// `true` if the object store has a key generator
+    pub auto_increment: bool,
+    /// Indexes of the object store, ordered by name
+    pub indexes: Vec<IndexSchema>,
+}
+
+impl ObjectStoreSchema {
+    /// Returns the schema of the index with the given name.
+    pub fn index(&self, name: &str) -> Option<&IndexSchema> {
+        self.indexes.iter().find(|index| index.name == name)
+    }
+}
+
+/// Snapshot of the schema of an index, returned by [`Index::schema`](crate::Index::schema).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexSchema {
+    /// Name of the index
+    pub name: String,
+    /// Key path of the index
+    pub key_path: KeyPath,
+    /// `true` if the index does not allow duplicate keys
+    pub unique: bool,
+    /// `true` if the index adds an entry for every element of array keys
+    pub multi_entry: bool,
+}
@@ -0,0 +1,45 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{builder::ObjectStoreBuilder, Error, Transaction, TypedObjectStore};
+
+/// A Rust type whose values are stored in an object store.
+///
+/// This trait is usually implemented using the [`IdbStore`](derive@crate::IdbStore) derive macro, which keeps the
+/// declaration of the object store (and its indexes) next to the struct:
+///
+/// ```rust
+/// use idb::{builder::DatabaseBuilder, IdbStore};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, IdbStore)]
+/// #[idb(store = "employees", key_path = "id", auto_increment)]
+/// struct Employee {
+///     #[serde(skip_serializing_if = "Option::is_none")]
+///     id: Option<u32>,
+///     name: String,
+///     #[idb(index, unique)]
+///     email: String,
+/// }
+///
+/// let builder = DatabaseBuilder::new("test")
+///     .version(1)
+///     .add_object_store(Employee::object_store_builder());
+/// ```
+///
+/// Besides implementing this trait, the derive macro adds a `<field>_index` function returning a
+/// [`TypedIndex`](crate::TypedIndex) for every index (`Employee::email_index` in the example above).
+pub trait IdbStore: Serialize + DeserializeOwned + 'static {
+    /// Type of the keys of the object store.
+    type Key: Serialize + DeserializeOwned + 'static;
+
+    /// Name of the object store.
+    const STORE_NAME: &'static str;
+
+    /// Returns the [`ObjectStoreBuilder`] declaring the object store and its indexes.
+    fn object_store_builder() -> ObjectStoreBuilder;
+
+    /// Returns the object store in the transaction's scope.
+    fn object_store(transaction: &Transaction) -> Result<TypedObjectStore<Self::Key, Self>, Error> {
+        transaction.typed_object_store(Self::STORE_NAME)
+    }
+}
@@ -1,14 +1,22 @@
+mod transaction_durability;
 #[cfg(feature = "futures")]
 mod transaction_future;
 mod transaction_mode;
+mod transaction_options;
 
 #[cfg(feature = "futures")]
 pub use self::transaction_future::{TransactionFuture, TransactionResult};
-pub use self::transaction_mode::TransactionMode;
+pub use self::{
+    transaction_durability::TransactionDurability, transaction_mode::TransactionMode,
+    transaction_options::TransactionOptions,
+};
 
+use js_sys::Reflect;
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
 use web_sys::{DomException, Event, EventTarget, IdbTransaction};
 
+#[cfg(feature = "serde")]
+use crate::TypedObjectStore;
 use crate::{utils::dom_string_list_to_vec, Database, Error, ObjectStore};
 
 /// Provides a static, asynchronous transaction on a database. All reading and writing of data is done within
@@ -37,6 +45,19 @@ impl Transaction {
             .try_into()
     }
 
+    /// Returns the durability hint the transaction was created with. Returns [`TransactionDurability::Default`] if the
+    /// browser does not support durability hints.
+    pub fn durability(&self) -> Result<TransactionDurability, Error> {
+        let durability = Reflect::get(&self.inner, &JsValue::from_str("durability"))
+            .map_err(Error::TransactionDurabilityNotFound)?;
+
+        if durability.is_undefined() {
+            Ok(TransactionDurability::Default)
+        } else {
+            durability.try_into()
+        }
+    }
+
     /// Returns the transaction’s connection.
     pub fn database(&self) -> Database {
         self.inner.db().into()
@@ -55,6 +76,17 @@ impl Transaction {
             .map_err(Error::ObjectStoreNotFound)
     }
 
+    /// Returns a [`TypedObjectStore`] in the transaction's scope whose keys are of type `K` and values are of type `V`.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(any(docsrs, feature = "doc"), doc(cfg(feature = "serde")))]
+    pub fn typed_object_store<K, V>(&self, name: &str) -> Result<TypedObjectStore<K, V>, Error>
+    where
+        K: serde::Serialize + serde::de::DeserializeOwned + 'static,
+        V: serde::Serialize + serde::de::DeserializeOwned + 'static,
+    {
+        self.object_store(name).map(Into::into)
+    }
+
     /// Attempts to commit the transaction. All pending requests will be allowed to complete, but no new requests will
     /// be accepted. This can be used to force a transaction to quickly finish, without waiting for pending requests to
     /// fire success events before attempting to commit normally.
@@ -104,6 +136,12 @@ impl Transaction {
         self.error_callback = Some(closure);
     }
 
+    /// Returns the underlying `IDBTransaction`.
+    #[cfg(feature = "changes")]
+    pub(crate) fn as_inner(&self) -> &IdbTransaction {
+        &self.inner
+    }
+
     /// Release memory management of the callbacks to JS GC.
     ///
     /// > Note: This may leak memory. Read more about it
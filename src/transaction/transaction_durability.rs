@@ -0,0 +1,47 @@
+use wasm_bindgen::JsValue;
+
+use crate::Error;
+
+/// Specifies the durability guarantee of a transaction, as a hint to the browser.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionDurability {
+    /// The browser uses its default durability behavior for the storage bucket.
+    #[default]
+    Default,
+    /// The transaction is only considered committed once all outstanding changes are written to a persistent storage
+    /// medium.
+    Strict,
+    /// The transaction is considered committed once all outstanding changes are written to the operating system,
+    /// without waiting for them to be flushed to a persistent storage medium. This is faster, but changes may be lost
+    /// on power failure or system crash.
+    Relaxed,
+}
+
+impl TransactionDurability {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            TransactionDurability::Default => "default",
+            TransactionDurability::Strict => "strict",
+            TransactionDurability::Relaxed => "relaxed",
+        }
+    }
+}
+
+impl TryFrom<JsValue> for TransactionDurability {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        match value.as_string().as_deref() {
+            Some("default") => Ok(TransactionDurability::Default),
+            Some("strict") => Ok(TransactionDurability::Strict),
+            Some("relaxed") => Ok(TransactionDurability::Relaxed),
+            _ => Err(Error::InvalidTransactionDurability),
+        }
+    }
+}
+
+impl From<TransactionDurability> for JsValue {
+    fn from(durability: TransactionDurability) -> Self {
+        JsValue::from_str(durability.as_str())
+    }
+}
@@ -7,7 +7,7 @@ use std::{
 use tokio::sync::oneshot;
 use web_sys::Event;
 
-use crate::{Error, Transaction};
+use crate::{Error, EventListener, Transaction, TransactionDurability};
 
 /// An enum that represents the result state of a transaction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -31,38 +31,71 @@ impl TransactionResult {
 }
 
 /// Future that resolved when transaction is completed. Either successfully or with an error.
+///
+/// The future listens for the events of the transaction using `addEventListener`, so handlers set using
+/// [`Transaction::on_complete`], [`Transaction::on_abort`] or [`Transaction::on_error`] do not prevent it from
+/// resolving.
 pub struct TransactionFuture {
-    _inner: Transaction,
+    inner: Transaction,
+    /// Error raised when adding the event listeners, returned when the future is polled
+    listen_error: Option<Error>,
+    _listeners: Vec<EventListener>,
     abort_receiver: oneshot::Receiver<()>,
     success_receiver: oneshot::Receiver<()>,
     error_receiver: oneshot::Receiver<Error>,
 }
 
+impl TransactionFuture {
+    /// Returns the durability hint the transaction was created with.
+    pub fn durability(&self) -> Result<TransactionDurability, Error> {
+        self.inner.durability()
+    }
+}
+
 impl IntoFuture for Transaction {
     type Output = <Self::IntoFuture as Future>::Output;
 
     type IntoFuture = TransactionFuture;
 
-    fn into_future(mut self) -> Self::IntoFuture {
+    fn into_future(self) -> Self::IntoFuture {
         let (abort_sender, abort_receiver) = oneshot::channel();
         let (error_sender, error_receiver) = oneshot::channel();
         let (success_sender, success_receiver) = oneshot::channel();
 
-        self.on_abort(move |_| {
-            let _ = abort_sender.send(());
-        });
-
-        self.on_complete(move |_| {
-            let _ = success_sender.send(());
-        });
-
-        self.on_error(move |event| {
-            let result = error_callback(event);
-            let _ = error_sender.send(result);
-        });
+        let mut abort_sender = Some(abort_sender);
+        let mut success_sender = Some(success_sender);
+        // `error` is fired for every failed request of the transaction, only the first one is sent
+        let mut error_sender = Some(error_sender);
+
+        let listeners = [
+            EventListener::new(&self.inner, "abort", move |_| {
+                if let Some(sender) = abort_sender.take() {
+                    let _ = sender.send(());
+                }
+            }),
+            EventListener::new(&self.inner, "complete", move |_| {
+                if let Some(sender) = success_sender.take() {
+                    let _ = sender.send(());
+                }
+            }),
+            EventListener::new(&self.inner, "error", move |event| {
+                if let Some(sender) = error_sender.take() {
+                    let _ = sender.send(error_callback(event));
+                }
+            }),
+        ]
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>();
+
+        let (listeners, listen_error) = match listeners {
+            Ok(listeners) => (listeners, None),
+            Err(err) => (Vec::new(), Some(err)),
+        };
 
         Self::IntoFuture {
-            _inner: self,
+            inner: self,
+            listen_error,
+            _listeners: listeners,
             abort_receiver,
             success_receiver,
             error_receiver,
@@ -76,6 +109,10 @@ impl Future for TransactionFuture {
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
 
+        if let Some(err) = this.listen_error.take() {
+            return Poll::Ready(Err(err));
+        }
+
         if let Poll::Ready(err) = Pin::new(&mut this.error_receiver).poll(cx) {
             return match err {
                 Ok(err) => Poll::Ready(Err(err)),
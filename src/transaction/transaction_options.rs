@@ -0,0 +1,45 @@
+use js_sys::{Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::{Error, TransactionDurability};
+
+/// Options when creating a [`Transaction`](crate::Transaction).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TransactionOptions {
+    inner: Object,
+}
+
+impl TransactionOptions {
+    /// Creates an new instance of [`TransactionOptions`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the durability hint of the transaction. Browsers which do not support durability hints ignore it.
+    pub fn durability(&mut self, durability: TransactionDurability) -> &mut Self {
+        // Setting a property on a plain object cannot fail
+        let _ = Reflect::set(
+            &self.inner,
+            &JsValue::from_str("durability"),
+            &durability.into(),
+        );
+        self
+    }
+}
+
+impl TryFrom<JsValue> for TransactionOptions {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        value
+            .dyn_into::<Object>()
+            .map(|inner| Self { inner })
+            .map_err(|value| Error::UnexpectedJsType("Object", value))
+    }
+}
+
+impl From<TransactionOptions> for JsValue {
+    fn from(value: TransactionOptions) -> Self {
+        value.inner.into()
+    }
+}
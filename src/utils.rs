@@ -1,5 +1,12 @@
+#[cfg(feature = "futures")]
+use std::time::Duration;
+
 use js_sys::Array;
+#[cfg(feature = "futures")]
+use js_sys::{Function, Reflect};
 use wasm_bindgen::JsValue;
+#[cfg(feature = "futures")]
+use wasm_bindgen::{prelude::Closure, JsCast};
 use web_sys::DomStringList;
 
 pub fn dom_string_list_to_vec(list: &DomStringList) -> Vec<String> {
@@ -22,3 +29,69 @@ pub fn array_to_vec(array: Array) -> Vec<JsValue> {
     }
     vec
 }
+
+#[cfg(feature = "serde")]
+pub fn to_js_value<T>(value: &T) -> Result<JsValue, crate::Error>
+where
+    T: serde::Serialize + ?Sized,
+{
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|err| crate::Error::SerializeFailed(err.into()))
+}
+
+#[cfg(feature = "serde")]
+pub fn from_js_value<T>(value: JsValue) -> Result<T, crate::Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_wasm_bindgen::from_value(value).map_err(|err| crate::Error::DeserializeFailed(err.into()))
+}
+
+/// A callback scheduled using `setTimeout`. The callback is cancelled when the [`Timeout`] is dropped.
+#[cfg(feature = "futures")]
+#[derive(Debug)]
+pub struct Timeout {
+    id: JsValue,
+    _callback: Closure<dyn FnMut()>,
+}
+
+#[cfg(feature = "futures")]
+impl Timeout {
+    /// Schedules the callback to be called after the given duration.
+    pub fn new<F>(duration: Duration, callback: F) -> Result<Self, crate::Error>
+    where
+        F: FnOnce() + 'static,
+    {
+        let callback = Closure::once(callback);
+
+        let id = global_function("setTimeout")?
+            .call2(
+                &js_sys::global(),
+                callback.as_ref(),
+                &JsValue::from_f64(duration.as_millis() as f64),
+            )
+            .map_err(crate::Error::SetTimeoutFailed)?;
+
+        Ok(Self {
+            id,
+            _callback: callback,
+        })
+    }
+}
+
+#[cfg(feature = "futures")]
+impl Drop for Timeout {
+    fn drop(&mut self) {
+        if let Ok(clear_timeout) = global_function("clearTimeout") {
+            let _ = clear_timeout.call1(&js_sys::global(), &self.id);
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+fn global_function(name: &str) -> Result<Function, crate::Error> {
+    Reflect::get(&js_sys::global(), &JsValue::from_str(name))
+        .and_then(|function| function.dyn_into::<Function>())
+        .map_err(crate::Error::SetTimeoutFailed)
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "backup")]
+
+use idb::{
+    backup,
+    builder::{DatabaseBuilder, IndexBuilder, ObjectStoreBuilder},
+    Factory, Key, KeyPath, TransactionMode,
+};
+use js_sys::{Date, Map, Object, Reflect, Uint8Array};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn set(object: &Object, name: &str, value: &JsValue) {
+    Reflect::set(object, &JsValue::from_str(name), value).unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_backup_export_import() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+    factory.delete("test-copy").unwrap().await.unwrap();
+
+    let database = DatabaseBuilder::new("test")
+        .version(3)
+        .add_object_store(
+            ObjectStoreBuilder::new("employees")
+                .key_path(Some(KeyPath::new_single("id")))
+                .add_index(
+                    IndexBuilder::new("email".to_owned(), KeyPath::new_single("email"))
+                        .unique(true),
+                ),
+        )
+        .add_object_store(ObjectStoreBuilder::new("files"))
+        .build()
+        .await
+        .unwrap();
+
+    let employee = Object::new();
+    set(&employee, "id", &JsValue::from(1));
+    set(&employee, "email", &JsValue::from_str("john@example.com"));
+    set(
+        &employee,
+        "joined",
+        &Date::new(&JsValue::from_f64(1_700_000_000_000.0)),
+    );
+    set(&employee, "score", &JsValue::from_f64(f64::NAN));
+    set(&employee, "manager", &JsValue::UNDEFINED);
+
+    let tag = Object::new();
+    set(&tag, "$type", &JsValue::from_str("not a tag"));
+    set(&employee, "tag", &tag);
+
+    let map = Map::new();
+    map.set(&JsValue::from_str("a"), &JsValue::from(1));
+    set(&employee, "map", &map);
+
+    let transaction = database
+        .transaction(&["employees", "files"], TransactionMode::ReadWrite)
+        .unwrap();
+    transaction
+        .object_store("employees")
+        .unwrap()
+        .put(&employee, None)
+        .unwrap()
+        .await
+        .unwrap();
+    transaction
+        .object_store("files")
+        .unwrap()
+        .put(
+            &Uint8Array::from([1u8, 2, 3].as_slice()).into(),
+            Some(&Uint8Array::from([0xffu8, 0].as_slice()).buffer().into()),
+        )
+        .unwrap()
+        .await
+        .unwrap();
+    transaction.commit().unwrap().await.unwrap();
+
+    let exported = backup::export(&database).await.unwrap();
+    let lines = exported.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with(r#"{"format":"idb-backup","version":1,"schema":"#));
+    assert!(lines[1].contains(r#""joined":{"$type":"date","value":1700000000000}"#));
+    assert!(lines[1].contains(r#""tag":{"$type":"Object","value":{"$type":"not a tag"}}"#));
+    assert!(lines[2].contains(r#""key":{"$type":"ArrayBuffer","value":"ff00"}"#));
+    assert!(lines[2].contains(r#""value":{"$type":"Uint8Array","value":"010203"}"#));
+
+    let copy = backup::import_as("test-copy", &exported).await.unwrap();
+    assert_eq!(copy.version(), Ok(3));
+
+    let copy_schema = copy.schema().unwrap();
+    assert_eq!(
+        copy_schema.object_stores,
+        database.schema().unwrap().object_stores
+    );
+
+    let transaction = copy
+        .transaction(&["employees", "files"], TransactionMode::ReadOnly)
+        .unwrap();
+    let restored = transaction
+        .object_store("employees")
+        .unwrap()
+        .get(JsValue::from(1))
+        .unwrap()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let joined = Reflect::get(&restored, &JsValue::from_str("joined")).unwrap();
+    assert_eq!(Date::from(joined).get_time(), 1_700_000_000_000.0);
+    assert!(Reflect::get(&restored, &JsValue::from_str("score"))
+        .unwrap()
+        .as_f64()
+        .unwrap()
+        .is_nan());
+    assert!(Reflect::has(&restored, &JsValue::from_str("manager")).unwrap());
+
+    let file = transaction
+        .object_store("files")
+        .unwrap()
+        .get(Key::Binary(vec![0xff, 0]))
+        .unwrap()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(Uint8Array::from(file).to_vec(), vec![1, 2, 3]);
+    transaction.await.unwrap();
+
+    // Exporting the copy gives the same records
+    let exported_copy = backup::export(&copy).await.unwrap();
+    assert_eq!(
+        exported_copy.lines().skip(1).collect::<Vec<_>>(),
+        lines[1..].to_vec()
+    );
+
+    database.close();
+    copy.close();
+    factory.delete("test").unwrap().await.unwrap();
+    factory.delete("test-copy").unwrap().await.unwrap();
+}
@@ -1,7 +1,18 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
 use idb::{
-    builder::{DatabaseBuilder, IndexBuilder, ObjectStoreBuilder},
-    Factory, KeyPath, TransactionMode,
+    builder::{
+        DatabaseBuilder, IndexBuilder, Migration, ObjectStoreBuilder, SchemaOperation, UpgradeStep,
+    },
+    CursorDirection, Error, Factory, KeyPath, TransactionMode,
 };
+use serde::Serialize;
+use serde_wasm_bindgen::Serializer;
+use wasm_bindgen::JsValue;
 use wasm_bindgen_test::wasm_bindgen_test;
 
 #[wasm_bindgen_test]
@@ -121,7 +132,9 @@ async fn test_database_builder_rename() {
         .add_object_store(ObjectStoreBuilder::new("store2"))
         .add_object_store(ObjectStoreBuilder::new("store3_to_rename_twice"))
         .rename_object_store("store3_to_rename_twice", "store3_to_rename_once_more")
+        .unwrap()
         .rename_object_store("store3_to_rename_once_more", "store3")
+        .unwrap()
         .build()
         .await
         .unwrap();
@@ -158,6 +171,7 @@ async fn test_database_builder_reopen_rename() {
         .version(2)
         .add_object_store(ObjectStoreBuilder::new("store_to_rename"))
         .rename_object_store("store_to_rename", "store")
+        .unwrap()
         .build()
         .await
         .unwrap();
@@ -196,6 +210,7 @@ async fn test_database_builder_reopen_remove_rename() {
         .add_object_store(ObjectStoreBuilder::new("store_to_rename"))
         .remove_object_store("store")
         .rename_object_store("store_to_rename", "store")
+        .unwrap()
         .build()
         .await
         .unwrap();
@@ -328,3 +343,367 @@ async fn test_database_builder_reopen() {
     transaction.abort().unwrap();
     database.close();
 }
+
+#[wasm_bindgen_test]
+async fn test_database_builder_migrations() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let database = DatabaseBuilder::new("test")
+        .version(1)
+        .add_object_store(
+            ObjectStoreBuilder::new("legacy").key_path(Some(KeyPath::new_single("id"))),
+        )
+        .build()
+        .await
+        .unwrap();
+
+    let transaction = database
+        .transaction(&["legacy"], TransactionMode::ReadWrite)
+        .unwrap();
+
+    let store = transaction.object_store("legacy").unwrap();
+
+    for (id, name) in [(1, "john"), (2, "jane")] {
+        let value = serde_json::json!({ "id": id, "name": name });
+        store
+            .add(
+                &value.serialize(&Serializer::json_compatible()).unwrap(),
+                None,
+            )
+            .unwrap()
+            .await
+            .unwrap();
+    }
+
+    transaction.commit().unwrap().await.unwrap();
+    database.close();
+
+    let applied = Rc::new(RefCell::new(Vec::new()));
+
+    let (applied1, applied2, applied3) = (applied.clone(), applied.clone(), applied.clone());
+
+    // Upgrading from version 1 to 3 runs the migrations for versions 2 and 3, in order
+    let database = DatabaseBuilder::new("test")
+        .add_object_store(
+            ObjectStoreBuilder::new("people").key_path(Some(KeyPath::new_single("id"))),
+        )
+        .add_migration(Migration::new(
+            3,
+            move |_, transaction, old_version| async move {
+                applied3.borrow_mut().push((3, old_version));
+
+                let legacy = transaction.object_store("legacy")?;
+                let people = transaction.object_store("people")?;
+
+                for value in legacy.get_all(None, None)?.await? {
+                    people.add(&value, None)?.await?;
+                }
+
+                Ok(())
+            },
+        ))
+        .add_migration(Migration::new(1, move |_, _, old_version| async move {
+            applied1.borrow_mut().push((1, old_version));
+            Ok(())
+        }))
+        .add_migration(Migration::new(
+            2,
+            move |_, transaction, old_version| async move {
+                applied2.borrow_mut().push((2, old_version));
+
+                let mut cursor = transaction
+                    .object_store("legacy")?
+                    .open_cursor(None, Some(CursorDirection::Next))?
+                    .await?
+                    .unwrap()
+                    .into_managed();
+
+                while let Some(value) = cursor.value()? {
+                    let name = js_sys::Reflect::get(&value, &JsValue::from_str("name"))
+                        .unwrap()
+                        .as_string()
+                        .unwrap();
+                    js_sys::Reflect::set(
+                        &value,
+                        &JsValue::from_str("name"),
+                        &JsValue::from_str(&name.to_uppercase()),
+                    )
+                    .unwrap();
+
+                    cursor.update(&value).await?;
+                    cursor.next(None).await?;
+                }
+
+                Ok(())
+            },
+        ))
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(database.version(), Ok(3));
+    assert_eq!(*applied.borrow(), vec![(2, 1), (3, 1)]);
+
+    // Stores not added to the builder are deleted after the migrations
+    assert_eq!(database.store_names(), vec!["people"]);
+
+    let transaction = database
+        .transaction(&["people"], TransactionMode::ReadOnly)
+        .unwrap();
+
+    let people = transaction
+        .object_store("people")
+        .unwrap()
+        .get_all(None, None)
+        .unwrap()
+        .await
+        .unwrap();
+
+    let names: Vec<_> = people
+        .iter()
+        .map(|person| {
+            js_sys::Reflect::get(person, &JsValue::from_str("name"))
+                .unwrap()
+                .as_string()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(names, vec!["JOHN", "JANE"]);
+
+    transaction.await.unwrap();
+    database.close();
+
+    // A failing migration aborts the upgrade
+    let result = DatabaseBuilder::new("test")
+        .version(4)
+        .add_object_store(
+            ObjectStoreBuilder::new("people").key_path(Some(KeyPath::new_single("id"))),
+        )
+        .add_migration(Migration::new(4, |_, _, _| async {
+            Err(Error::TransactionNotFound)
+        }))
+        .build()
+        .await;
+
+    assert_eq!(
+        result.unwrap_err(),
+        Error::UpgradeFailed {
+            step: UpgradeStep::Migration(4),
+            source: Box::new(Error::TransactionNotFound),
+        }
+    );
+
+    let database = factory.open("test", None).unwrap().await.unwrap();
+    assert_eq!(database.version(), Ok(3));
+    database.close();
+
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+fn test_database_builder_rename_missing_store() {
+    let result = DatabaseBuilder::new("test")
+        .add_object_store(ObjectStoreBuilder::new("store"))
+        .rename_object_store("missing", "renamed");
+
+    assert!(matches!(result, Err(Error::ObjectStoreNotFound(_))));
+}
+
+#[wasm_bindgen_test]
+async fn test_database_builder_upgrade_failed() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    // `multi_entry` indexes cannot have an array key path
+    let result = DatabaseBuilder::new("test")
+        .version(1)
+        .add_object_store(ObjectStoreBuilder::new("store1"))
+        .add_object_store(
+            ObjectStoreBuilder::new("store2").add_index(
+                IndexBuilder::new("index".to_string(), KeyPath::new_array(["a", "b"]))
+                    .multi_entry(true),
+            ),
+        )
+        .build()
+        .await;
+
+    match result {
+        Err(Error::UpgradeFailed { step, .. }) => assert_eq!(
+            step,
+            UpgradeStep::CreateIndex {
+                object_store: "store2".to_string(),
+                index: "index".to_string(),
+            }
+        ),
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    // The upgrade transaction is aborted, so the database is not created
+    let database = factory.open("test", None).unwrap().await.unwrap();
+    assert_eq!(database.version(), Ok(1));
+    assert!(database.store_names().is_empty());
+    database.close();
+
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_database_builder_blocked_timeout() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    // A connection which does not close itself on `versionchange`
+    let database = factory.open("test", Some(1)).unwrap().await.unwrap();
+
+    let blocked = Rc::new(RefCell::new(Vec::new()));
+    let blocked_versions = blocked.clone();
+
+    let result = DatabaseBuilder::new("test")
+        .version(2)
+        .on_blocked(move |old, new| blocked_versions.borrow_mut().push((old, new)))
+        .blocked_timeout(Duration::from_millis(50))
+        .build()
+        .await;
+
+    assert!(
+        matches!(result, Err(Error::UpgradeBlocked { old: 1, new: 2 })),
+        "unexpected result: {result:?}"
+    );
+    assert_eq!(*blocked.borrow(), vec![(1, 2)]);
+
+    // The timed out upgrade is aborted once unblocked
+    database.close();
+
+    let database = factory.open("test", None).unwrap().await.unwrap();
+    assert_eq!(database.version(), Ok(1));
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_database_builder_version_change() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let version_changes = Rc::new(RefCell::new(Vec::new()));
+    let database_version_changes = version_changes.clone();
+
+    let mut database = DatabaseBuilder::new("test")
+        .version(1)
+        .on_version_change(move |old, new| database_version_changes.borrow_mut().push((old, new)))
+        .build()
+        .await
+        .unwrap();
+
+    // Handlers set on the connection do not replace the one closing it
+    let handled = Rc::new(Cell::new(false));
+    let database_handled = handled.clone();
+    database.on_version_change(move |_| database_handled.set(true));
+
+    // The first connection closes itself so that the upgrade is not blocked
+    let upgraded = DatabaseBuilder::new("test")
+        .version(2)
+        .blocked_timeout(Duration::from_millis(50))
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(upgraded.version(), Ok(2));
+    assert_eq!(*version_changes.borrow(), vec![(1, Some(2))]);
+    assert!(handled.get());
+
+    database.close();
+    upgraded.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_database_builder_plan() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let builder = || {
+        DatabaseBuilder::new("test").version(1).add_object_store(
+            ObjectStoreBuilder::new("store1").add_index(IndexBuilder::new(
+                "index1".to_owned(),
+                KeyPath::new_single("id"),
+            )),
+        )
+    };
+
+    let plan = builder().plan().await.unwrap();
+    assert_eq!(plan.old_version, 0);
+    assert_eq!(plan.new_version, 1);
+    assert_eq!(
+        plan.operations,
+        vec![
+            SchemaOperation::CreateObjectStore("store1".to_owned()),
+            SchemaOperation::CreateIndex {
+                object_store: "store1".to_owned(),
+                index: "index1".to_owned(),
+            },
+        ]
+    );
+    assert!(!plan.is_destructive());
+
+    let database = builder()
+        .add_object_store(ObjectStoreBuilder::new("store2"))
+        .build()
+        .await
+        .unwrap();
+    database.close();
+
+    let upgrade = || {
+        DatabaseBuilder::new("test")
+            .version(2)
+            .add_object_store(ObjectStoreBuilder::new("store1").add_index(
+                IndexBuilder::new("index1".to_owned(), KeyPath::new_single("id")).unique(true),
+            ))
+            .add_object_store(ObjectStoreBuilder::new("store3"))
+    };
+
+    let plan = upgrade().plan().await.unwrap();
+    assert_eq!(plan.old_version, 1);
+    assert_eq!(plan.new_version, 2);
+    assert_eq!(
+        plan.operations,
+        vec![
+            SchemaOperation::RecreateIndex {
+                object_store: "store1".to_owned(),
+                index: "index1".to_owned(),
+            },
+            SchemaOperation::CreateObjectStore("store3".to_owned()),
+            SchemaOperation::DeleteObjectStore("store2".to_owned()),
+        ]
+    );
+    assert_eq!(plan.destructive_operations().count(), 2);
+
+    let result = upgrade().allow_destructive(false).build().await;
+    assert_eq!(
+        result.unwrap_err(),
+        Error::DestructiveUpgrade(vec![
+            SchemaOperation::RecreateIndex {
+                object_store: "store1".to_owned(),
+                index: "index1".to_owned(),
+            },
+            SchemaOperation::DeleteObjectStore("store2".to_owned()),
+        ])
+    );
+
+    // The refused upgrade left the database unchanged
+    let database = factory.open("test", None).unwrap().await.unwrap();
+    assert_eq!(database.version(), Ok(1));
+    assert_eq!(database.store_names(), vec!["store1", "store2"]);
+    database.close();
+
+    let database = upgrade().build().await.unwrap();
+    assert_eq!(database.store_names(), vec!["store1", "store3"]);
+    database.close();
+
+    let plan = upgrade().plan().await.unwrap();
+    assert!(plan.operations.is_empty());
+
+    factory.delete("test").unwrap().await.unwrap();
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "changes")]
+
+use futures::{FutureExt, StreamExt};
+use idb::{
+    ChangeFeed, ChangeKind, DatabaseEvent, Factory, Key, ObjectStoreParams, TransactionMode,
+};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn test_change_feed() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        database
+            .create_object_store("store1", ObjectStoreParams::new())
+            .unwrap();
+        database
+            .create_object_store("store2", ObjectStoreParams::new())
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    let feed = ChangeFeed::new("test").unwrap();
+    let mut store1_changes = feed.store_changes("store1");
+    let mut all_changes = feed.changes();
+
+    // Changes of aborted transactions are not published
+    let transaction = database
+        .transaction(&["store1"], TransactionMode::ReadWrite)
+        .unwrap();
+    feed.watch(&transaction);
+
+    let store = transaction.object_store("store1").unwrap();
+    store
+        .put(&JsValue::from_str("aborted"), Some(&JsValue::from(0)))
+        .unwrap()
+        .await
+        .unwrap();
+
+    let result = transaction.abort().unwrap().await.unwrap();
+    assert!(result.is_aborted());
+    assert!(all_changes.next().now_or_never().is_none());
+
+    // Changes of committed transactions are published once the transaction commits
+    let transaction = database
+        .transaction(&["store1", "store2"], TransactionMode::ReadWrite)
+        .unwrap();
+    feed.watch(&transaction);
+
+    let store1 = transaction.object_store("store1").unwrap();
+    let store2 = transaction.object_store("store2").unwrap();
+
+    store1
+        .put(&JsValue::from_str("one"), Some(&JsValue::from(1)))
+        .unwrap()
+        .await
+        .unwrap();
+    store2
+        .add(&JsValue::from_str("two"), Some(&JsValue::from(2)))
+        .unwrap()
+        .await
+        .unwrap();
+    store1.delete(JsValue::from(1)).unwrap().await.unwrap();
+
+    assert!(all_changes.next().now_or_never().is_none());
+
+    let result = transaction.commit().unwrap().await.unwrap();
+    assert!(result.is_committed());
+
+    let change = store1_changes.next().await.unwrap();
+    assert_eq!(change.store_name, "store1");
+    assert_eq!(change.kind, ChangeKind::Put);
+    assert_eq!(change.key, Some(Key::Number(1.0)));
+
+    let change = store1_changes.next().await.unwrap();
+    assert_eq!(change.kind, ChangeKind::Delete);
+    assert_eq!(change.key, Some(Key::Number(1.0)));
+
+    assert!(store1_changes.next().now_or_never().is_none());
+
+    let kinds = [
+        all_changes.next().await.unwrap(),
+        all_changes.next().await.unwrap(),
+        all_changes.next().await.unwrap(),
+    ]
+    .map(|change| (change.store_name, change.kind));
+    assert_eq!(
+        kinds,
+        [
+            ("store1".to_owned(), ChangeKind::Put),
+            ("store2".to_owned(), ChangeKind::Add),
+            ("store1".to_owned(), ChangeKind::Delete),
+        ]
+    );
+
+    // Changes are received by the other feeds of the database
+    let other_feed = ChangeFeed::new("test").unwrap();
+    let mut other_changes = other_feed.changes();
+
+    let transaction = database
+        .transaction(&["store1"], TransactionMode::ReadWrite)
+        .unwrap();
+    feed.watch(&transaction);
+    transaction
+        .object_store("store1")
+        .unwrap()
+        .clear()
+        .unwrap()
+        .await
+        .unwrap();
+    transaction.commit().unwrap().await.unwrap();
+
+    let change = other_changes.next().await.unwrap();
+    assert_eq!(change.store_name, "store1");
+    assert_eq!(change.kind, ChangeKind::Clear);
+    assert_eq!(change.key, None);
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
@@ -1,6 +1,7 @@
+use futures::{StreamExt, TryStreamExt};
 use idb::{
-    CursorDirection, DatabaseEvent, Factory, IndexParams, KeyPath, ObjectStoreParams,
-    TransactionMode,
+    request::RequestSource, CursorDirection, CursorSource, DatabaseEvent, Factory, IndexParams,
+    Key, KeyPath, ObjectStoreParams, Request, TransactionMode,
 };
 use serde::Serialize;
 use serde_wasm_bindgen::Serializer;
@@ -83,9 +84,9 @@ async fn test_cursor_next_advance_and_get() {
         .unwrap()
         .into_managed();
 
-    assert_eq!(Ok(Some(id1.clone())), cursor.key());
+    assert_eq!(Ok(Some(Key::try_from(id1.clone()).unwrap())), cursor.key());
     cursor.next(None).await.unwrap();
-    assert_eq!(Ok(Some(id2.clone())), cursor.key());
+    assert_eq!(Ok(Some(Key::try_from(id2.clone()).unwrap())), cursor.key());
     cursor.next(None).await.unwrap();
     assert_eq!(Ok(None), cursor.key());
 
@@ -107,6 +108,7 @@ async fn test_cursor_next_advance_and_get() {
         .into_managed();
 
     let mut num_employees = 0;
+    #[allow(clippy::while_let_loop)]
     loop {
         match cursor.value().unwrap() {
             Some(_) => {
@@ -135,9 +137,9 @@ async fn test_cursor_next_advance_and_get() {
         .unwrap()
         .into_managed();
 
-    assert_eq!(Ok(Some(id1)), cursor.key());
+    assert_eq!(Ok(Some(Key::try_from(id1).unwrap())), cursor.key());
     cursor.advance(1).await.unwrap();
-    assert_eq!(Ok(Some(id2)), cursor.key());
+    assert_eq!(Ok(Some(Key::try_from(id2).unwrap())), cursor.key());
     cursor.advance(1).await.unwrap();
     assert_eq!(Ok(None), cursor.key());
 
@@ -224,9 +226,9 @@ async fn test_cursor_delete() {
         .unwrap()
         .into_managed();
 
-    assert_eq!(Ok(Some(id1.clone())), cursor.key());
+    assert_eq!(Ok(Some(Key::try_from(id1.clone()).unwrap())), cursor.key());
     cursor.next(None).await.unwrap();
-    assert_eq!(Ok(Some(id2.clone())), cursor.key());
+    assert_eq!(Ok(Some(Key::try_from(id2.clone()).unwrap())), cursor.key());
 
     cursor.delete().await.unwrap();
 
@@ -292,3 +294,198 @@ async fn test_cursor_with_zero_matches() {
     database.close();
     factory.delete("test").unwrap().await.unwrap();
 }
+
+#[wasm_bindgen_test]
+async fn test_cursor_stream() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        let mut store_params = ObjectStoreParams::new();
+        store_params.auto_increment(true);
+        store_params.key_path(Some(KeyPath::new_single("id")));
+
+        let store = database
+            .create_object_store("employees", store_params)
+            .unwrap();
+
+        store
+            .create_index("email", KeyPath::new_single("email"), None)
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    // Insert multiple values
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadWrite)
+        .unwrap();
+
+    let store = transaction.object_store("employees").unwrap();
+
+    for email in ["c@example.com", "a@example.com", "b@example.com"] {
+        let employee = serde_json::json!({ "email": email });
+        store
+            .add(
+                &employee.serialize(&Serializer::json_compatible()).unwrap(),
+                None,
+            )
+            .unwrap()
+            .await
+            .unwrap();
+    }
+
+    transaction.commit().unwrap().await.unwrap();
+
+    // Test store cursor stream
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadOnly)
+        .unwrap();
+
+    let store = transaction.object_store("employees").unwrap();
+
+    let records: Vec<_> = store
+        .open_cursor(None, Some(CursorDirection::Next))
+        .unwrap()
+        .await
+        .unwrap()
+        .unwrap()
+        .into_managed()
+        .into_stream()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(3, records.len());
+    for (i, (key, primary_key, value)) in records.iter().enumerate() {
+        let id = Key::from(i as u32 + 1);
+        assert_eq!(&id, key);
+        assert_eq!(&id, primary_key);
+        assert_eq!(
+            Some(i as f64 + 1.0),
+            js_sys::Reflect::get(value, &"id".into()).unwrap().as_f64()
+        );
+    }
+
+    // Take only part of an index key cursor stream
+    let index = store.index("email").unwrap();
+
+    let keys: Vec<_> = index
+        .open_key_cursor(None, Some(CursorDirection::Next))
+        .unwrap()
+        .await
+        .unwrap()
+        .unwrap()
+        .into_managed()
+        .into_stream()
+        .take(2)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            (Key::from("a@example.com"), Key::from(2)),
+            (Key::from("b@example.com"), Key::from(3)),
+        ],
+        keys
+    );
+
+    transaction.await.unwrap();
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_request_and_cursor_source() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        let mut store_params = ObjectStoreParams::new();
+        store_params.auto_increment(true);
+        store_params.key_path(Some(KeyPath::new_single("id")));
+
+        let store = database
+            .create_object_store("employees", store_params)
+            .unwrap();
+
+        store
+            .create_index("email", KeyPath::new_single("email"), None)
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    // Open requests do not have a source
+    let open_request = factory.open("test", None).unwrap();
+    assert!(open_request.source().is_err());
+    open_request.await.unwrap().close();
+
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadWrite)
+        .unwrap();
+    let store = transaction.object_store("employees").unwrap();
+    let index = store.index("email").unwrap();
+
+    let employee = serde_json::json!({
+        "name": "John Doe",
+        "email": "john@example.com",
+    });
+    let employee = employee.serialize(&Serializer::json_compatible()).unwrap();
+
+    let add_request = store.add(&employee, None).unwrap();
+    assert_eq!(
+        add_request.source().unwrap(),
+        RequestSource::ObjectStore(store.clone())
+    );
+    add_request.await.unwrap();
+
+    let get_request = index.get(Key::from("john@example.com")).unwrap();
+    let source = get_request.source().unwrap();
+    assert_eq!(source, RequestSource::Index(index.clone()));
+    assert_eq!(source.object_store().unwrap().name(), "employees");
+    get_request.await.unwrap();
+
+    // Cursors report the index they were opened from
+    let cursor = index
+        .open_cursor(None, None)
+        .unwrap()
+        .await
+        .unwrap()
+        .unwrap();
+    let cursor_source = cursor.source().unwrap();
+    assert_eq!(cursor_source, CursorSource::Index(index.clone()));
+    assert_eq!(cursor_source.name(), "email");
+
+    // Requests made against cursors report the cursor (the stored value has the generated `id`)
+    let update_request = cursor.update(&cursor.value().unwrap()).unwrap();
+    match update_request.source().unwrap() {
+        RequestSource::Cursor(source) => assert_eq!(source, cursor),
+        source => panic!("unexpected request source: {source:?}"),
+    }
+    update_request.await.unwrap();
+
+    let key_cursor = store
+        .open_key_cursor(None, None)
+        .unwrap()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        key_cursor.source().unwrap(),
+        CursorSource::ObjectStore(store.clone())
+    );
+
+    transaction.await.unwrap();
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
@@ -1,4 +1,11 @@
-use idb::{DatabaseEvent, Factory, ObjectStoreParams, TransactionMode};
+use std::{cell::Cell, rc::Rc};
+
+use futures::StreamExt;
+use idb::{
+    builder::{DatabaseBuilder, IndexBuilder, ObjectStoreBuilder},
+    DatabaseEvent, DatabaseLifecycleEvent, DatabaseSchema, Factory, IndexSchema, KeyPath,
+    ObjectStoreParams, ObjectStoreSchema, TransactionMode,
+};
 use wasm_bindgen_test::wasm_bindgen_test;
 
 #[wasm_bindgen_test]
@@ -142,3 +149,143 @@ async fn test_database_delete_object_store() {
     database.close();
     factory.delete("test").unwrap().await.unwrap();
 }
+
+#[wasm_bindgen_test]
+async fn test_database_events() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let database = factory.open("test", Some(1)).unwrap().await.unwrap();
+
+    let notifications = Rc::new(Cell::new(0));
+
+    let first_notifications = notifications.clone();
+    let first_listener = database
+        .add_version_change_listener(move |_| {
+            first_notifications.set(first_notifications.get() + 1)
+        })
+        .unwrap();
+
+    let second_notifications = notifications.clone();
+    let second_listener = database
+        .add_version_change_listener(move |event| {
+            second_notifications.set(second_notifications.get() + 1);
+
+            // Close the connection so that the upgrade is not blocked
+            event.database().unwrap().close();
+        })
+        .unwrap();
+    assert_eq!(second_listener.event_type(), "versionchange");
+
+    let mut events = database.events().unwrap();
+
+    let upgraded = factory.open("test", Some(2)).unwrap().await.unwrap();
+    assert_eq!(upgraded.version(), Ok(2));
+    assert_eq!(notifications.get(), 2);
+
+    let event = events.next().await.unwrap();
+    assert!(
+        matches!(
+            event,
+            DatabaseLifecycleEvent::VersionChange {
+                old: 1,
+                new: Some(2)
+            }
+        ),
+        "unexpected event: {event:?}"
+    );
+
+    first_listener.remove();
+    second_listener.remove();
+
+    upgraded.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_database_schema() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let database = DatabaseBuilder::new("test")
+        .version(1)
+        .add_object_store(
+            ObjectStoreBuilder::new("employees")
+                .auto_increment(true)
+                .key_path(Some(KeyPath::new_single("id")))
+                .add_index(
+                    IndexBuilder::new("email".to_owned(), KeyPath::new_single("email"))
+                        .unique(true),
+                )
+                .add_index(
+                    IndexBuilder::new("tags".to_owned(), KeyPath::new_single("tags"))
+                        .multi_entry(true),
+                ),
+        )
+        .add_object_store(ObjectStoreBuilder::new("settings"))
+        .build()
+        .await
+        .unwrap();
+
+    let schema = database.schema().unwrap();
+    assert_eq!(
+        schema,
+        DatabaseSchema {
+            name: "test".to_owned(),
+            version: 1,
+            object_stores: vec![
+                ObjectStoreSchema {
+                    name: "employees".to_owned(),
+                    key_path: Some(KeyPath::new_single("id")),
+                    auto_increment: true,
+                    indexes: vec![
+                        IndexSchema {
+                            name: "email".to_owned(),
+                            key_path: KeyPath::new_single("email"),
+                            unique: true,
+                            multi_entry: false,
+                        },
+                        IndexSchema {
+                            name: "tags".to_owned(),
+                            key_path: KeyPath::new_single("tags"),
+                            unique: false,
+                            multi_entry: true,
+                        },
+                    ],
+                },
+                ObjectStoreSchema {
+                    name: "settings".to_owned(),
+                    key_path: None,
+                    auto_increment: false,
+                    indexes: vec![],
+                },
+            ],
+        }
+    );
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(json["object_stores"][0]["key_path"], "id");
+        assert_eq!(
+            serde_json::from_value::<DatabaseSchema>(json).unwrap(),
+            schema
+        );
+    }
+
+    database.close();
+
+    // Rebuilding the database from its schema does not change it
+    let database = DatabaseBuilder::from(schema.clone())
+        .version(2)
+        .build()
+        .await
+        .unwrap();
+
+    let rebuilt_schema = database.schema().unwrap();
+    assert_eq!(rebuilt_schema.version, 2);
+    assert_eq!(rebuilt_schema.object_stores, schema.object_stores);
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "derive")]
+
+use idb::{builder::DatabaseBuilder, Factory, IdbStore, KeyPath, TransactionMode};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, IdbStore)]
+#[serde(rename_all = "camelCase")]
+#[idb(store = "employees", key_path = "id", auto_increment)]
+struct Employee {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<u32>,
+    full_name: String,
+    #[idb(index, unique)]
+    email_address: String,
+    #[idb(index = "by_tag", multi_entry)]
+    tags: Vec<String>,
+}
+
+#[wasm_bindgen_test]
+async fn test_derive_idb_store() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let database = DatabaseBuilder::new("test")
+        .version(1)
+        .add_object_store(Employee::object_store_builder())
+        .build()
+        .await
+        .unwrap();
+
+    let schema = database.schema().unwrap();
+    let store_schema = schema.object_store(Employee::STORE_NAME).unwrap();
+    assert_eq!(store_schema.key_path, Some(KeyPath::new_single("id")));
+    assert!(store_schema.auto_increment);
+
+    let email_index = store_schema.index("emailAddress").unwrap();
+    assert_eq!(email_index.key_path, KeyPath::new_single("emailAddress"));
+    assert!(email_index.unique);
+
+    let tag_index = store_schema.index("by_tag").unwrap();
+    assert_eq!(tag_index.key_path, KeyPath::new_single("tags"));
+    assert!(tag_index.multi_entry);
+
+    let transaction = database
+        .transaction(&[Employee::STORE_NAME], TransactionMode::ReadWrite)
+        .unwrap();
+    let store = Employee::object_store(&transaction).unwrap();
+
+    let id = store
+        .add(
+            &Employee {
+                id: None,
+                full_name: "John Doe".to_owned(),
+                email_address: "john@example.com".to_owned(),
+                tags: vec!["admin".to_owned(), "sales".to_owned()],
+            },
+            None,
+        )
+        .unwrap()
+        .await
+        .unwrap();
+
+    let by_email = Employee::email_address_index(&store)
+        .unwrap()
+        .get(&"john@example.com".to_owned())
+        .unwrap()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(by_email.id, Some(id));
+    assert_eq!(by_email.full_name, "John Doe");
+
+    let by_tag = Employee::tags_index(&store)
+        .unwrap()
+        .get(&"sales".to_owned())
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(by_tag, Some(by_email));
+
+    transaction.commit().unwrap().await.unwrap();
+    database.close();
+}
@@ -0,0 +1,86 @@
+use idb::{DatabaseEvent, Error, Factory, IdbErrorKind, ObjectStoreParams, TransactionMode};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::DomException;
+
+#[wasm_bindgen_test]
+fn test_error_kind_from_dom_exception() {
+    let exception = DomException::new_with_message_and_name("", "QuotaExceededError").unwrap();
+    let error = Error::DomException(exception.clone());
+    assert_eq!(error.kind(), Some(IdbErrorKind::QuotaExceeded));
+    assert!(error.is_quota_exceeded());
+    assert!(!error.is_constraint_violation());
+
+    let error = Error::AddFailed(exception.into());
+    assert_eq!(error.kind(), Some(IdbErrorKind::QuotaExceeded));
+
+    let error = Error::AddFailed(JsValue::from_str("not an exception"));
+    assert_eq!(error.kind(), None);
+
+    assert_eq!(Error::CursorFinished.kind(), None);
+
+    assert_eq!(
+        IdbErrorKind::from_name("TransactionInactiveError"),
+        IdbErrorKind::TransactionInactive
+    );
+    assert_eq!(
+        IdbErrorKind::from_name("SomethingElse"),
+        IdbErrorKind::Other("SomethingElse".to_string())
+    );
+    assert_eq!(IdbErrorKind::Version.name(), "VersionError");
+}
+
+#[wasm_bindgen_test]
+async fn test_error_kind_from_failed_request() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        database
+            .create_object_store("store", ObjectStoreParams::new())
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    let transaction = database
+        .transaction(&["store"], TransactionMode::ReadWrite)
+        .unwrap();
+
+    let store = transaction.object_store("store").unwrap();
+
+    let key = JsValue::from_str("key");
+    store
+        .add(&JsValue::from_str("value"), Some(&key))
+        .unwrap()
+        .await
+        .unwrap();
+
+    let error = store
+        .add(&JsValue::from_str("value"), Some(&key))
+        .unwrap()
+        .await
+        .unwrap_err();
+    assert!(error.is_constraint_violation());
+    assert_eq!(error.kind(), Some(IdbErrorKind::Constraint));
+
+    let _ = transaction.await;
+
+    // Requests on finished transactions fail
+    let error = store.get(key).unwrap_err();
+    assert!(error.is_transaction_inactive());
+
+    database.close();
+
+    // Opening with a lower version fails
+    let database = factory.open("test", Some(2)).unwrap().await.unwrap();
+    database.close();
+
+    let error = factory.open("test", Some(1)).unwrap().await.unwrap_err();
+    assert!(error.is_version_error());
+
+    factory.delete("test").unwrap().await.unwrap();
+}
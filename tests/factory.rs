@@ -1,6 +1,7 @@
-use std::future::IntoFuture;
+use std::{cmp::Ordering, future::IntoFuture};
 
-use idb::Factory;
+use idb::{DatabaseInfo, Error, Factory, IdbErrorKind};
+use wasm_bindgen::JsValue;
 use wasm_bindgen_test::wasm_bindgen_test;
 
 #[wasm_bindgen_test]
@@ -71,3 +72,51 @@ async fn test_factory_open_request_future_drop() {
     let fut = open_request.unwrap().into_future();
     drop(fut);
 }
+
+#[wasm_bindgen_test]
+async fn test_factory_databases() {
+    let factory = Factory::new().unwrap();
+
+    let database = factory
+        .open("test_databases", Some(3))
+        .unwrap()
+        .await
+        .unwrap();
+    database.close();
+
+    let databases = factory.databases().await.unwrap();
+    assert!(databases.contains(&DatabaseInfo {
+        name: "test_databases".to_string(),
+        version: 3,
+    }));
+
+    factory.delete("test_databases").unwrap().await.unwrap();
+
+    let databases = factory.databases().await.unwrap();
+    assert!(databases
+        .iter()
+        .all(|database| database.name != "test_databases"));
+}
+
+#[wasm_bindgen_test]
+fn test_factory_cmp() {
+    let factory = Factory::new().unwrap();
+
+    assert_eq!(
+        factory.cmp(&JsValue::from(1), &JsValue::from(2)),
+        Ok(Ordering::Less)
+    );
+    assert_eq!(
+        factory.cmp(&JsValue::from_str("a"), &JsValue::from_str("a")),
+        Ok(Ordering::Equal)
+    );
+    // Strings are greater than numbers
+    assert_eq!(
+        factory.cmp(&JsValue::from_str("a"), &JsValue::from(2)),
+        Ok(Ordering::Greater)
+    );
+
+    let result = factory.cmp(&JsValue::TRUE, &JsValue::from(1));
+    assert!(matches!(result, Err(Error::KeyCompareFailed(_))));
+    assert_eq!(result.unwrap_err().kind(), Some(IdbErrorKind::Data));
+}
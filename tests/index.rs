@@ -1,6 +1,6 @@
 use idb::{
-    DatabaseEvent, Factory, IndexParams, KeyPath, KeyRange, ObjectStoreParams, Query,
-    TransactionMode,
+    Continuation, CursorDirection, Database, DatabaseEvent, Error, Factory, GetAllOptions,
+    IndexParams, Key, KeyPath, KeyRange, ObjectStoreParams, Query, Queryable, TransactionMode,
 };
 use serde::Serialize;
 use serde_json::Value;
@@ -144,8 +144,8 @@ async fn test_index_read() {
         .get_all(
             Some(Query::KeyRange(
                 KeyRange::bound(
-                    &JsValue::from_str("ja"),
-                    &JsValue::from_str("jo"),
+                    JsValue::from_str("ja"),
+                    JsValue::from_str("jo"),
                     Some(false),
                     Some(true),
                 )
@@ -171,3 +171,323 @@ async fn test_index_read() {
     database.close();
     factory.delete("test").unwrap().await.unwrap();
 }
+
+/// Creates a database with an `employees` object store (keyed by `id`) with a `team` index and adds `count` employees
+/// to it, with even and odd ids in the `even` and `odd` teams respectively.
+async fn open_employees_with_teams(count: u32) -> (Factory, Database) {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        let mut store_params = ObjectStoreParams::new();
+        store_params.key_path(Some(KeyPath::new_single("id")));
+
+        let store = database
+            .create_object_store("employees", store_params)
+            .unwrap();
+
+        store
+            .create_index("team", KeyPath::new_single("team"), None)
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadWrite)
+        .unwrap();
+    let store = transaction.object_store("employees").unwrap();
+
+    for id in 1..=count {
+        let employee = serde_json::json!({
+            "id": id,
+            "team": if id % 2 == 0 { "even" } else { "odd" },
+        });
+
+        store
+            .add(
+                &employee.serialize(&Serializer::json_compatible()).unwrap(),
+                None,
+            )
+            .unwrap()
+            .await
+            .unwrap();
+    }
+
+    transaction.commit().unwrap().await.unwrap();
+
+    (factory, database)
+}
+
+#[wasm_bindgen_test]
+async fn test_index_page() {
+    // Ten employees, five in each team
+    let (factory, database) = open_employees_with_teams(10).await;
+
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadOnly)
+        .unwrap();
+    let store = transaction.object_store("employees").unwrap();
+    let index = store.index("team").unwrap();
+
+    // Pages of the index resume exactly, even in the middle of duplicate keys
+    let mut primary_keys = Vec::new();
+    let mut after: Option<Continuation> = None;
+    let mut pages = 0;
+
+    loop {
+        let page = index.page(None, None, 3, after.as_ref()).await.unwrap();
+        pages += 1;
+
+        primary_keys.extend(page.records.into_iter().map(|record| record.primary_key));
+
+        match page.continuation {
+            // Continuations survive a round trip through their string representation
+            Some(continuation) => after = Some(continuation.to_string().parse().unwrap()),
+            None => break,
+        }
+    }
+
+    assert_eq!(pages, 4);
+    assert_eq!(
+        primary_keys,
+        [2, 4, 6, 8, 10, 1, 3, 5, 7, 9].map(Key::from).to_vec()
+    );
+
+    // Continuation tokens only contain URL safe characters
+    let continuation = Continuation::new(
+        Key::Array(vec![Key::from("a b/c?d=é"), Key::from(1)]),
+        Key::from("&#"),
+    );
+    let token = continuation.to_string();
+    assert!(token
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == ':'));
+    assert_eq!(token.parse(), Ok(continuation));
+
+    // Deeply nested array keys are rejected
+    let token = format!("{}s0:", "a1:".repeat(1000));
+    assert_eq!(
+        token.parse::<Continuation>(),
+        Err(Error::InvalidContinuation)
+    );
+
+    // Pages of the object store in reverse order
+    let page = store
+        .page(None, Some(CursorDirection::Prev), 4, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        page.records
+            .iter()
+            .map(|record| record.key.clone())
+            .collect::<Vec<_>>(),
+        [10, 9, 8, 7].map(Key::from).to_vec()
+    );
+
+    let page = store
+        .page(
+            None,
+            Some(CursorDirection::Prev),
+            10,
+            page.continuation.as_ref(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(page.records.len(), 6);
+    assert_eq!(page.records[0].key, Key::from(6));
+    assert_eq!(page.continuation, None);
+
+    transaction.await.unwrap();
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+/// Hides the native `getAllRecords()` of an object store or index (and with it, support for `IDBGetAllOptions`) so
+/// that the cursor fallback is used.
+fn hide_get_all_records(source: impl Into<JsValue>) {
+    js_sys::Reflect::set(
+        &source.into(),
+        &JsValue::from_str("getAllRecords"),
+        &JsValue::UNDEFINED,
+    )
+    .unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_index_get_all_records() {
+    let (factory, database) = open_employees_with_teams(6).await;
+
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadOnly)
+        .unwrap();
+    let store = transaction.object_store("employees").unwrap();
+    let index = store.index("team").unwrap();
+
+    // Natively where supported, then using a cursor
+    for fallback in [false, true] {
+        if fallback {
+            hide_get_all_records(index.clone());
+        }
+
+        // Records of the index matching a query, with index keys and primary keys
+        let records = index
+            .get_all_records(Query::from(Key::from("odd")).into())
+            .await
+            .unwrap();
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| (record.key.clone(), record.primary_key.clone()))
+                .collect::<Vec<_>>(),
+            [1, 3, 5]
+                .map(|id| (Key::from("odd"), Key::from(id)))
+                .to_vec()
+        );
+
+        let value: Value = serde_wasm_bindgen::from_value(records[2].value.clone()).unwrap();
+        assert_eq!(value, serde_json::json!({ "id": 5, "team": "odd" }));
+
+        // In reverse order and limited by count
+        let records = index
+            .get_all_records(GetAllOptions {
+                limit: Some(4),
+                direction: Some(CursorDirection::Prev),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| record.primary_key.clone())
+                .collect::<Vec<_>>(),
+            [5, 3, 1, 6].map(Key::from).to_vec()
+        );
+
+        // A unique direction only returns the first record of every index key
+        let records = index
+            .get_all_records(GetAllOptions {
+                direction: Some(CursorDirection::NextUnique),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].key, Key::from("even"));
+        assert_eq!(records[0].primary_key, Key::from(2));
+    }
+
+    transaction.await.unwrap();
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_index_get_all_with_options() {
+    let (factory, database) = open_employees_with_teams(6).await;
+
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadOnly)
+        .unwrap();
+    let store = transaction.object_store("employees").unwrap();
+    let index = store.index("team").unwrap();
+
+    // Natively where supported, then using a cursor
+    for fallback in [false, true] {
+        if fallback {
+            hide_get_all_records(index.clone());
+        }
+
+        // Values and primary keys of the index in reverse order, limited by count
+        let values = index
+            .get_all_with_options(GetAllOptions {
+                query: Some(Query::from(Key::from("even"))),
+                limit: Some(2),
+                direction: Some(CursorDirection::Prev),
+            })
+            .await
+            .unwrap();
+        let values: Vec<Value> = values
+            .into_iter()
+            .map(|value| serde_wasm_bindgen::from_value(value).unwrap())
+            .collect();
+        assert_eq!(
+            values,
+            [6, 4].map(|id| serde_json::json!({ "id": id, "team": "even" }))
+        );
+
+        let primary_keys = index
+            .get_all_keys_with_options(GetAllOptions {
+                direction: Some(CursorDirection::PrevUnique),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        // Unique cursors visit the record with the lowest primary key of every index key, even in reverse order
+        assert_eq!(primary_keys, [JsValue::from(1), JsValue::from(2)]);
+    }
+
+    // Forward options use plain `getAll()` requests
+    let primary_keys = store
+        .get_all_keys_with_options(GetAllOptions {
+            limit: Some(3),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(primary_keys, [1, 2, 3].map(JsValue::from));
+
+    transaction.await.unwrap();
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+/// Counts the records matching query and returns the primary keys of the first page of them.
+async fn count_and_first_page<S: Queryable>(source: &S, query: Option<Query>) -> (u32, Vec<Key>) {
+    let count = source.count(query.clone()).unwrap().await.unwrap();
+    let page = source.page(query, None, 2, None).await.unwrap();
+
+    (
+        count,
+        page.records
+            .into_iter()
+            .map(|record| record.primary_key)
+            .collect(),
+    )
+}
+
+#[wasm_bindgen_test]
+async fn test_queryable() {
+    let (factory, database) = open_employees_with_teams(5).await;
+
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadOnly)
+        .unwrap();
+    let store = transaction.object_store("employees").unwrap();
+    let index = store.index("team").unwrap();
+
+    // The same generic code works with object stores and indexes
+    assert_eq!(Queryable::name(&store), "employees");
+    assert_eq!(Queryable::name(&index), "team");
+
+    assert_eq!(
+        count_and_first_page(&store, None).await,
+        (5, [1, 2].map(Key::from).to_vec())
+    );
+    assert_eq!(
+        count_and_first_page(&index, Some(Query::from(Key::from("odd")))).await,
+        (3, [1, 3].map(Key::from).to_vec())
+    );
+
+    transaction.await.unwrap();
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
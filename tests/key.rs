@@ -0,0 +1,175 @@
+use idb::{Error, Key, KeyRange, Query};
+use js_sys::{Array, Date, Uint8Array};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_key_conversion() {
+    let keys = vec![
+        Key::Number(1.5),
+        Key::Date(1_000.0),
+        Key::String("hello".to_string()),
+        Key::Binary(vec![1, 2, 3]),
+        Key::Array(vec![Key::Number(1.0), Key::String("a".to_string())]),
+    ];
+
+    for key in keys {
+        let value = JsValue::from(&key);
+        assert_eq!(Key::try_from(value), Ok(key));
+    }
+
+    let date: JsValue = Date::new(&JsValue::from_f64(42.0)).into();
+    assert_eq!(Key::try_from(date), Ok(Key::Date(42.0)));
+
+    let typed_array: JsValue = Uint8Array::from([4u8, 5, 6].as_slice()).into();
+    assert_eq!(Key::try_from(typed_array), Ok(Key::Binary(vec![4, 5, 6])));
+}
+
+#[wasm_bindgen_test]
+fn test_key_validation() {
+    let nan = JsValue::from_f64(f64::NAN);
+    assert_eq!(Key::try_from(nan.clone()), Err(Error::InvalidKey(nan)));
+
+    let invalid_date: JsValue = Date::new(&JsValue::from_f64(f64::NAN)).into();
+    assert!(matches!(
+        Key::try_from(invalid_date),
+        Err(Error::InvalidKey(_))
+    ));
+
+    assert!(matches!(
+        Key::try_from(JsValue::TRUE),
+        Err(Error::InvalidKey(_))
+    ));
+    assert!(matches!(
+        Key::try_from(JsValue::NULL),
+        Err(Error::InvalidKey(_))
+    ));
+
+    let cyclic = Array::new();
+    cyclic.push(&cyclic);
+    assert!(matches!(
+        Key::try_from(JsValue::from(cyclic)),
+        Err(Error::InvalidKey(_))
+    ));
+
+    assert!(!Key::Array(vec![Key::Number(f64::NAN)]).is_valid());
+}
+
+#[wasm_bindgen_test]
+fn test_key_ordering() {
+    let mut keys = vec![
+        Key::Array(vec![Key::Number(1.0), Key::Number(2.0)]),
+        Key::Array(vec![Key::Number(1.0)]),
+        Key::Binary(vec![1, 2]),
+        Key::Binary(vec![1]),
+        Key::String("\u{FF61}".to_string()),
+        Key::String("\u{1F600}".to_string()),
+        Key::String("a".to_string()),
+        Key::Date(0.0),
+        Key::Number(10.0),
+        Key::Number(-1.0),
+    ];
+    keys.sort();
+
+    assert_eq!(
+        keys,
+        vec![
+            Key::Number(-1.0),
+            Key::Number(10.0),
+            Key::Date(0.0),
+            Key::String("a".to_string()),
+            // Strings are compared by UTF-16 code units, so surrogate pairs come before U+FF61
+            Key::String("\u{1F600}".to_string()),
+            Key::String("\u{FF61}".to_string()),
+            Key::Binary(vec![1]),
+            Key::Binary(vec![1, 2]),
+            Key::Array(vec![Key::Number(1.0)]),
+            Key::Array(vec![Key::Number(1.0), Key::Number(2.0)]),
+        ]
+    );
+
+    assert_eq!(Key::Number(0.0), Key::Number(-0.0));
+}
+
+#[wasm_bindgen_test]
+fn test_key_in_query_and_key_range() {
+    let query = Query::from(Key::from("hello"));
+    assert_eq!(query, Query::Key(JsValue::from_str("hello")));
+
+    let key_range = KeyRange::bound(Key::from(1), Key::from(5), None, Some(true)).unwrap();
+    assert_eq!(key_range.lower(), Ok(Some(Key::Number(1.0))));
+    assert_eq!(key_range.upper(), Ok(Some(Key::Number(5.0))));
+    assert_eq!(key_range.includes(Key::from(3)), Ok(true));
+    assert_eq!(key_range.includes(Key::from(5)), Ok(false));
+
+    let key_range = KeyRange::lower_bound(Key::from("a"), None).unwrap();
+    assert_eq!(key_range.upper(), Ok(None));
+}
+
+#[wasm_bindgen_test]
+fn test_key_range_builder() {
+    let key_range = KeyRange::from_key(1).to_exclusive(10).unwrap();
+    assert_eq!(key_range.lower(), Ok(Some(Key::from(1))));
+    assert_eq!(key_range.upper(), Ok(Some(Key::from(10))));
+    assert!(!key_range.lower_open());
+    assert!(key_range.upper_open());
+
+    let key_range = KeyRange::builder().lower_exclusive("m").build().unwrap();
+    assert_eq!(key_range.includes("m"), Ok(false));
+    assert_eq!(key_range.includes("n"), Ok(true));
+    assert_eq!(key_range.upper(), Ok(None));
+
+    assert!(matches!(
+        KeyRange::builder().build(),
+        Err(Error::InvalidKeyRange(_))
+    ));
+    assert!(KeyRange::from_key(5).to(1).is_err());
+
+    let key_range = KeyRange::try_from(1..=5).unwrap();
+    assert_eq!(key_range.includes(5), Ok(true));
+
+    let key_range = KeyRange::try_from(1..5).unwrap();
+    assert_eq!(key_range.includes(5), Ok(false));
+
+    let key_range = KeyRange::try_from("b"..).unwrap();
+    assert_eq!(key_range.includes("z"), Ok(true));
+    assert_eq!(key_range.includes("a"), Ok(false));
+
+    let key_range = KeyRange::try_from(..=Key::from(0)).unwrap();
+    assert_eq!(key_range.includes(0), Ok(true));
+    assert_eq!(key_range.lower(), Ok(None));
+}
+
+#[wasm_bindgen_test]
+fn test_key_range_starts_with() {
+    let key_range = KeyRange::starts_with("ab").unwrap();
+    assert_eq!(key_range.includes("ab"), Ok(true));
+    assert_eq!(key_range.includes("abc"), Ok(true));
+    assert_eq!(key_range.includes("ab\u{FFFF}z"), Ok(true));
+    assert_eq!(key_range.includes("ac"), Ok(false));
+    assert_eq!(key_range.includes("aa"), Ok(false));
+
+    let key_range = KeyRange::starts_with("").unwrap();
+    assert_eq!(key_range.includes("anything"), Ok(true));
+    assert_eq!(key_range.includes(Key::Binary(Vec::new())), Ok(false));
+
+    let key_range = KeyRange::starts_with(vec![Key::from("a"), Key::from(1)]).unwrap();
+    assert_eq!(
+        key_range.includes(Key::Array(vec![Key::from("a"), Key::from(1)])),
+        Ok(true)
+    );
+    assert_eq!(
+        key_range.includes(Key::Array(vec![
+            Key::from("a"),
+            Key::from(1),
+            Key::Array(vec![Key::from("z")]),
+        ])),
+        Ok(true)
+    );
+    assert_eq!(
+        key_range.includes(Key::Array(vec![Key::from("a"), Key::from(2)])),
+        Ok(false)
+    );
+
+    assert!(KeyRange::starts_with(1).is_err());
+}
@@ -0,0 +1,619 @@
+#![cfg(all(feature = "memory", not(target_arch = "wasm32")))]
+
+use futures::executor::block_on;
+use idb::{
+    backend::{
+        memory::{MemoryDatabase, MemoryFactory},
+        BackendCursor, BackendDatabase, BackendIndex, BackendObjectStore, BackendTransaction,
+        KeyQuery,
+    },
+    builder::{IndexBuilder, ObjectStoreBuilder},
+    CursorDirection, Error, IdbErrorKind, Key, KeyPath, TransactionMode, TransactionResult,
+};
+use serde_json::{json, Value};
+
+async fn open_employees(factory: &MemoryFactory) -> MemoryDatabase {
+    factory
+        .open("test", Some(1), |database, _, _| async move {
+            database.create_object_store(
+                ObjectStoreBuilder::new("employees")
+                    .key_path(Some(KeyPath::new_single("id")))
+                    .auto_increment(true)
+                    .add_index(
+                        IndexBuilder::new("email".to_owned(), KeyPath::new_single("email"))
+                            .unique(true),
+                    )
+                    .add_index(
+                        IndexBuilder::new("tags".to_owned(), KeyPath::new_single("tags"))
+                            .multi_entry(true),
+                    ),
+            )
+        })
+        .await
+        .unwrap()
+}
+
+/// Application code written against the backend traits.
+async fn add_employees<D: BackendDatabase>(
+    database: &D,
+    employees: &[Value],
+) -> Result<Vec<Key>, Error> {
+    let transaction = database.transaction(&["employees"], TransactionMode::ReadWrite)?;
+    let store = transaction.object_store("employees")?;
+
+    let mut ids = Vec::new();
+
+    for employee in employees {
+        ids.push(store.add(employee, None).await?);
+    }
+
+    transaction.commit().await?;
+
+    Ok(ids)
+}
+
+#[test]
+fn test_memory_open() {
+    block_on(async {
+        let factory = MemoryFactory::new();
+        let database = open_employees(&factory).await;
+
+        assert_eq!(database.name(), "test");
+        assert_eq!(database.version().unwrap(), 1);
+        assert_eq!(database.store_names(), vec!["employees".to_owned()]);
+
+        let database = factory.open("test", None, |_, _, _| async { Ok(()) }).await;
+        assert_eq!(database.unwrap().version().unwrap(), 1);
+
+        let database = factory
+            .open("test", Some(0), |_, _, _| async { Ok(()) })
+            .await;
+        assert!(database.is_err());
+
+        let error = factory
+            .open("other", Some(1), |_, _, _| async { Ok(()) })
+            .await
+            .and_then(|database| {
+                database.transaction(&["employees"], TransactionMode::ReadOnly)?;
+                Ok(())
+            })
+            .unwrap_err();
+        assert_eq!(error.kind(), Some(IdbErrorKind::NotFound));
+
+        let error = factory
+            .open("other", Some(2), |database, _, _| async move {
+                database.create_object_store(ObjectStoreBuilder::new("items"))?;
+                Err(Error::CursorFinished)
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::CursorFinished));
+
+        let databases = factory.databases();
+        assert_eq!(databases.len(), 2);
+        assert_eq!(databases[0].name, "other");
+        assert_eq!(databases[0].version, 1);
+
+        let database = factory
+            .open("test", Some(2), |database, _, old_version| async move {
+                assert_eq!(old_version, 1);
+                database.delete_object_store("employees")
+            })
+            .await
+            .unwrap();
+        assert!(database.store_names().is_empty());
+
+        let error = factory
+            .open("test", Some(1), |_, _, _| async { Ok(()) })
+            .await
+            .unwrap_err();
+        assert!(error.is_version_error());
+
+        factory.delete("test");
+        assert_eq!(factory.databases().len(), 1);
+    });
+}
+
+#[test]
+fn test_memory_key_paths_and_auto_increment() {
+    block_on(async {
+        let factory = MemoryFactory::new();
+        let database = open_employees(&factory).await;
+
+        let ids = add_employees(
+            &database,
+            &[
+                json!({ "name": "John Doe", "email": "john@example.com" }),
+                json!({ "id": 10, "name": "Jane Doe", "email": "jane@example.com" }),
+                json!({ "name": "Scooby Doo", "email": "scooby@example.com" }),
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(ids, vec![Key::from(1), Key::from(10), Key::from(11)]);
+
+        let transaction = database
+            .transaction(&["employees"], TransactionMode::ReadOnly)
+            .unwrap();
+        let store = transaction.object_store("employees").unwrap();
+
+        assert_eq!(store.key_path().unwrap(), Some(KeyPath::new_single("id")));
+        assert!(store.auto_increment());
+        assert_eq!(
+            store.index_names(),
+            vec!["email".to_owned(), "tags".to_owned()]
+        );
+
+        let employee = store.get(KeyQuery::only(1)).await.unwrap();
+        assert_eq!(
+            employee,
+            Some(json!({ "id": 1, "name": "John Doe", "email": "john@example.com" }))
+        );
+
+        let error = store
+            .add(&json!({ "name": "Read Only" }), None)
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), Some(IdbErrorKind::ReadOnly));
+
+        transaction.commit().await.unwrap();
+
+        let transaction = database
+            .transaction(&["employees"], TransactionMode::ReadWrite)
+            .unwrap();
+        let store = transaction.object_store("employees").unwrap();
+
+        let error = store
+            .add(&json!({ "id": 1, "email": "other@example.com" }), None)
+            .await
+            .unwrap_err();
+        assert!(error.is_constraint_violation());
+
+        let error = store.add(&json!({ "id": true }), None).await.unwrap_err();
+        assert_eq!(error.kind(), Some(IdbErrorKind::Data));
+
+        let error = store
+            .put(&json!({ "email": "new@example.com" }), Some(&Key::from(20)))
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), Some(IdbErrorKind::Data));
+
+        transaction.commit().await.unwrap();
+    });
+}
+
+#[test]
+fn test_memory_key_ordering() {
+    block_on(async {
+        let factory = MemoryFactory::new();
+        let database = factory
+            .open("test", Some(1), |database, _, _| async move {
+                database.create_object_store(ObjectStoreBuilder::new("keys"))
+            })
+            .await
+            .unwrap();
+
+        let transaction = database
+            .transaction(&["keys"], TransactionMode::ReadWrite)
+            .unwrap();
+        let store = transaction.object_store("keys").unwrap();
+
+        let keys = [
+            Key::Array(vec![Key::from(1)]),
+            Key::from("b"),
+            Key::Binary(vec![1, 2]),
+            Key::from(2),
+            Key::Date(0.0),
+            Key::from("a"),
+            Key::from(-1),
+        ];
+
+        for (i, key) in keys.iter().enumerate() {
+            store.put(&json!(i), Some(key)).await.unwrap();
+        }
+
+        let sorted = store.get_all_keys(None, None).await.unwrap();
+        assert_eq!(
+            sorted,
+            vec![
+                Key::from(-1),
+                Key::from(2),
+                Key::Date(0.0),
+                Key::from("a"),
+                Key::from("b"),
+                Key::Binary(vec![1, 2]),
+                Key::Array(vec![Key::from(1)]),
+            ]
+        );
+
+        let strings = store
+            .get_all(Some(KeyQuery::bound("a", "b", false, true)), None)
+            .await
+            .unwrap();
+        assert_eq!(strings, vec![json!(5)]);
+
+        assert_eq!(
+            store
+                .count(Some(KeyQuery::lower_bound("a", false)))
+                .await
+                .unwrap(),
+            4
+        );
+        assert_eq!(store.get_all(None, Some(2)).await.unwrap().len(), 2);
+        assert_eq!(
+            store
+                .count(Some(KeyQuery::bound("b", "a", false, false)))
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            store
+                .count(Some(KeyQuery::bound("a", "a", true, true)))
+                .await
+                .unwrap(),
+            0
+        );
+
+        store
+            .delete(KeyQuery::upper_bound(Key::Date(0.0), false))
+            .await
+            .unwrap();
+        assert_eq!(store.count(None).await.unwrap(), 4);
+
+        store.clear().await.unwrap();
+        assert_eq!(store.count(None).await.unwrap(), 0);
+
+        transaction.commit().await.unwrap();
+    });
+}
+
+#[test]
+fn test_memory_indexes() {
+    block_on(async {
+        let factory = MemoryFactory::new();
+        let database = open_employees(&factory).await;
+
+        add_employees(
+            &database,
+            &[
+                json!({ "name": "John Doe", "email": "john@example.com", "tags": ["a", "b", "a"] }),
+                json!({ "name": "Jane Doe", "email": "jane@example.com", "tags": ["b", "c"] }),
+                json!({ "name": "Scooby Doo", "tags": "c" }),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let transaction = database
+            .transaction(&["employees"], TransactionMode::ReadWrite)
+            .unwrap();
+        let store = transaction.object_store("employees").unwrap();
+
+        let email = store.index("email").unwrap();
+        assert!(email.unique());
+        assert!(!email.multi_entry());
+        assert_eq!(email.count(None).await.unwrap(), 2);
+
+        let jane = email.get(KeyQuery::only("jane@example.com")).await.unwrap();
+        assert_eq!(jane.unwrap()["name"], json!("Jane Doe"));
+
+        assert_eq!(
+            email.get_all_keys(None, None).await.unwrap(),
+            vec![Key::from(2), Key::from(1)]
+        );
+
+        let error = store
+            .add(&json!({ "email": "john@example.com" }), None)
+            .await
+            .unwrap_err();
+        assert!(error.is_constraint_violation());
+
+        store
+            .put(&json!({ "id": 1, "email": "john@example.com" }), None)
+            .await
+            .unwrap();
+
+        let tags = store.index("tags").unwrap();
+        assert!(tags.multi_entry());
+        assert_eq!(tags.count(None).await.unwrap(), 3);
+        assert_eq!(
+            tags.get_all_keys(Some(KeyQuery::only("c")), None)
+                .await
+                .unwrap(),
+            vec![Key::from(2), Key::from(3)]
+        );
+        assert!(tags
+            .get_all(Some(KeyQuery::only("a")), None)
+            .await
+            .unwrap()
+            .is_empty());
+
+        assert_eq!(
+            store.index("missing").unwrap_err().kind(),
+            Some(IdbErrorKind::NotFound)
+        );
+
+        transaction.commit().await.unwrap();
+    });
+}
+
+#[test]
+fn test_memory_abort() {
+    block_on(async {
+        let factory = MemoryFactory::new();
+        let database = open_employees(&factory).await;
+
+        add_employees(&database, &[json!({ "name": "John Doe" })])
+            .await
+            .unwrap();
+
+        let transaction = database
+            .transaction(&["employees"], TransactionMode::ReadWrite)
+            .unwrap();
+        let store = transaction.object_store("employees").unwrap();
+
+        store
+            .add(&json!({ "name": "Jane Doe" }), None)
+            .await
+            .unwrap();
+        store.delete(KeyQuery::only(1)).await.unwrap();
+        assert_eq!(store.count(None).await.unwrap(), 1);
+
+        assert_eq!(
+            transaction.abort().await.unwrap(),
+            TransactionResult::Aborted
+        );
+
+        let error = store.count(None).await.unwrap_err();
+        assert_eq!(error.kind(), Some(IdbErrorKind::TransactionInactive));
+
+        let ids = add_employees(&database, &[json!({ "name": "Scooby Doo" })])
+            .await
+            .unwrap();
+        assert_eq!(ids, vec![Key::from(2)]);
+
+        let transaction = database
+            .transaction(&["employees"], TransactionMode::ReadOnly)
+            .unwrap();
+        let store = transaction.object_store("employees").unwrap();
+        assert_eq!(
+            store.get_all_keys(None, None).await.unwrap(),
+            vec![Key::from(1), Key::from(2)]
+        );
+        transaction.commit().await.unwrap();
+
+        let error = factory
+            .open("test", Some(2), |database, transaction, _| async move {
+                database.delete_object_store("employees")?;
+                transaction.abort().await?;
+                Ok(())
+            })
+            .await
+            .unwrap_err();
+        assert!(error.is_aborted());
+        assert_eq!(database.version().unwrap(), 1);
+        assert_eq!(database.store_names(), vec!["employees".to_owned()]);
+    });
+}
+
+#[test]
+fn test_memory_overlapping_transactions() {
+    block_on(async {
+        let factory = MemoryFactory::new();
+        let database = factory
+            .open("test", Some(1), |database, _, _| async move {
+                for name in ["first", "second"] {
+                    database.create_object_store(
+                        ObjectStoreBuilder::new(name).key_path(Some(KeyPath::new_single("id"))),
+                    )?;
+                }
+
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let first = database
+            .transaction(&["first"], TransactionMode::ReadWrite)
+            .unwrap();
+        let first_store = first.object_store("first").unwrap();
+        first_store.put(&json!({ "id": 1 }), None).await.unwrap();
+
+        // Transactions overlapping an unfinished read-write transaction cannot see its changes
+        for mode in [TransactionMode::ReadWrite, TransactionMode::ReadOnly] {
+            let error = database
+                .transaction(&["second", "first"], mode)
+                .unwrap_err();
+            assert_eq!(error.kind(), Some(IdbErrorKind::Timeout));
+        }
+
+        // Transactions with disjoint scopes are independent
+        let second = database
+            .transaction(&["second"], TransactionMode::ReadWrite)
+            .unwrap();
+        second
+            .object_store("second")
+            .unwrap()
+            .put(&json!({ "id": 1, "v": "second" }), None)
+            .await
+            .unwrap();
+        assert_eq!(second.commit().await.unwrap(), TransactionResult::Committed);
+
+        assert_eq!(first.abort().await.unwrap(), TransactionResult::Aborted);
+
+        // Once the first transaction is finished, its object store can be used again
+        let transaction = database
+            .transaction(&["first", "second"], TransactionMode::ReadWrite)
+            .unwrap();
+        let first_store = transaction.object_store("first").unwrap();
+        let second_store = transaction.object_store("second").unwrap();
+
+        assert_eq!(first_store.get(KeyQuery::only(1)).await.unwrap(), None);
+        assert_eq!(
+            second_store.get(KeyQuery::only(1)).await.unwrap(),
+            Some(json!({ "id": 1, "v": "second" }))
+        );
+
+        first_store
+            .put(&json!({ "id": 1, "v": "third" }), None)
+            .await
+            .unwrap();
+        transaction.commit().await.unwrap();
+
+        // Read-only transactions can overlap each other, and dropped transactions do not block others
+        let read = database
+            .transaction(&["first"], TransactionMode::ReadOnly)
+            .unwrap();
+        let other_read = database
+            .transaction(&["first"], TransactionMode::ReadOnly)
+            .unwrap();
+        assert_eq!(
+            other_read
+                .object_store("first")
+                .unwrap()
+                .get(KeyQuery::only(1))
+                .await
+                .unwrap(),
+            Some(json!({ "id": 1, "v": "third" }))
+        );
+        drop(read);
+        drop(other_read);
+
+        database
+            .transaction(&["first"], TransactionMode::ReadWrite)
+            .unwrap();
+    });
+}
+
+#[test]
+fn test_memory_cursor() {
+    block_on(async {
+        let factory = MemoryFactory::new();
+        let database = open_employees(&factory).await;
+
+        add_employees(
+            &database,
+            &[
+                json!({ "name": "John Doe", "tags": ["a", "b"] }),
+                json!({ "name": "Jane Doe", "tags": ["b"] }),
+                json!({ "name": "Scooby Doo", "tags": ["c"] }),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let transaction = database
+            .transaction(&["employees"], TransactionMode::ReadWrite)
+            .unwrap();
+        let store = transaction.object_store("employees").unwrap();
+        let tags = store.index("tags").unwrap();
+
+        let mut entries = Vec::new();
+        let mut cursor = tags.open_cursor(None, None).await.unwrap().unwrap();
+        while let Some(key) = cursor.key().unwrap() {
+            entries.push((key, cursor.primary_key().unwrap().unwrap()));
+            cursor.next().await.unwrap();
+        }
+        assert_eq!(
+            entries,
+            vec![
+                (Key::from("a"), Key::from(1)),
+                (Key::from("b"), Key::from(1)),
+                (Key::from("b"), Key::from(2)),
+                (Key::from("c"), Key::from(3)),
+            ]
+        );
+        assert!(matches!(cursor.next().await, Err(Error::CursorFinished)));
+
+        let mut entries = Vec::new();
+        let mut cursor = tags
+            .open_cursor(None, Some(CursorDirection::PrevUnique))
+            .await
+            .unwrap()
+            .unwrap();
+        while let Some(key) = cursor.key().unwrap() {
+            entries.push((key, cursor.primary_key().unwrap().unwrap()));
+            cursor.next().await.unwrap();
+        }
+        assert_eq!(
+            entries,
+            vec![
+                (Key::from("c"), Key::from(3)),
+                (Key::from("b"), Key::from(1)),
+                (Key::from("a"), Key::from(1)),
+            ]
+        );
+
+        for (direction, expected) in [
+            (
+                CursorDirection::Prev,
+                vec![
+                    (Key::from("b"), Key::from(2)),
+                    (Key::from("b"), Key::from(1)),
+                ],
+            ),
+            (
+                CursorDirection::NextUnique,
+                vec![
+                    (Key::from("b"), Key::from(1)),
+                    (Key::from("c"), Key::from(3)),
+                ],
+            ),
+        ] {
+            let mut entries = Vec::new();
+            let mut cursor = tags
+                .open_cursor(
+                    Some(KeyQuery::bound(
+                        "b",
+                        "c",
+                        false,
+                        direction == CursorDirection::Prev,
+                    )),
+                    Some(direction),
+                )
+                .await
+                .unwrap()
+                .unwrap();
+            while let Some(key) = cursor.key().unwrap() {
+                entries.push((key, cursor.primary_key().unwrap().unwrap()));
+                cursor.next().await.unwrap();
+            }
+            assert_eq!(entries, expected);
+        }
+
+        let mut cursor = store
+            .open_cursor(None, Some(CursorDirection::Prev))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cursor.primary_key().unwrap(), Some(Key::from(3)));
+
+        cursor.delete().await.unwrap();
+        cursor.advance(1).await.unwrap();
+
+        let mut value = cursor.value().unwrap().unwrap();
+        value["name"] = json!("Jane Smith");
+        cursor.update(&value).await.unwrap();
+
+        value["id"] = json!(5);
+        let error = cursor.update(&value).await.unwrap_err();
+        assert_eq!(error.kind(), Some(IdbErrorKind::Data));
+
+        cursor.advance(2).await.unwrap();
+        assert_eq!(cursor.key().unwrap(), None);
+
+        assert!(store
+            .open_cursor(Some(KeyQuery::only(3)), None)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            store.get(KeyQuery::only(2)).await.unwrap().unwrap()["name"],
+            json!("Jane Smith")
+        );
+        assert_eq!(tags.count(None).await.unwrap(), 3);
+
+        transaction.commit().await.unwrap();
+    });
+}
@@ -1,5 +1,6 @@
 use idb::{
-    DatabaseEvent, Factory, IndexParams, KeyPath, ObjectStoreParams, Query, TransactionMode,
+    CursorDirection, DatabaseEvent, Factory, GetAllOptions, IndexParams, Key, KeyPath, KeyRange,
+    ObjectStoreParams, Query, TransactionMode,
 };
 use serde::Serialize;
 use serde_json::Value;
@@ -602,3 +603,179 @@ async fn test_bulk_insertion() {
     database.close();
     factory.delete("test").unwrap().await.unwrap();
 }
+
+#[wasm_bindgen_test]
+async fn test_object_store_batch() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        let mut store_params = ObjectStoreParams::new();
+        store_params.key_path(Some(KeyPath::new_single("id")));
+
+        let store = database
+            .create_object_store("employees", store_params)
+            .unwrap();
+
+        let mut index_params = IndexParams::new();
+        index_params.unique(true);
+
+        store
+            .create_index("email", KeyPath::new_single("email"), Some(index_params))
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadWrite)
+        .unwrap();
+
+    let store = transaction.object_store("employees").unwrap();
+
+    let employees = (1..=100).map(|id| {
+        serde_json::json!({
+            "id": id,
+            "email": format!("employee{id}@example.com"),
+        })
+        .serialize(&Serializer::json_compatible())
+        .unwrap()
+    });
+
+    let result = store.add_all(employees).await.unwrap();
+    assert!(result.is_ok());
+    assert_eq!(result.succeeded(), 100);
+    assert_eq!(result.keys[99], Some(JsValue::from(100)));
+
+    // Duplicate key and duplicate email
+    let employees = [
+        serde_json::json!({ "id": 101, "email": "employee101@example.com" }),
+        serde_json::json!({ "id": 1, "email": "other@example.com" }),
+        serde_json::json!({ "id": 102, "email": "employee2@example.com" }),
+        serde_json::json!({ "id": 103, "email": "employee103@example.com" }),
+    ]
+    .map(|employee| employee.serialize(&Serializer::json_compatible()).unwrap());
+
+    let result = store.add_all(employees).await.unwrap();
+    assert!(!result.is_ok());
+    assert_eq!(result.succeeded(), 2);
+    assert_eq!(result.failures.len(), 2);
+    assert_eq!(result.failures[0].index, 1);
+    assert_eq!(result.failures[0].key, Some(JsValue::from(1)));
+    assert!(result.failures[0].error.is_constraint_violation());
+    assert_eq!(result.failures[1].index, 2);
+    assert_eq!(result.failures[1].key, Some(JsValue::from(102)));
+    assert!(result.keys[1].is_none());
+    assert_eq!(result.keys[3], Some(JsValue::from(103)));
+
+    let result = store.delete_all((1..=50).map(JsValue::from)).await.unwrap();
+    assert!(result.is_ok());
+
+    transaction.commit().unwrap().await.unwrap();
+
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadOnly)
+        .unwrap();
+
+    let store = transaction.object_store("employees").unwrap();
+
+    assert_eq!(store.count(None).unwrap().await, Ok(52));
+
+    transaction.await.unwrap();
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_object_store_get_all_records() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        let mut store_params = ObjectStoreParams::new();
+        store_params.key_path(Some(KeyPath::new_single("id")));
+
+        database
+            .create_object_store("employees", store_params)
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadWrite)
+        .unwrap();
+    let store = transaction.object_store("employees").unwrap();
+
+    let employees = (1..=5).map(|id| {
+        serde_json::json!({ "id": id, "name": format!("Employee {id}") })
+            .serialize(&Serializer::json_compatible())
+            .unwrap()
+    });
+    assert!(store.add_all(employees).await.unwrap().is_ok());
+
+    // Natively where supported, then using a cursor (by hiding `getAllRecords()`)
+    for fallback in [false, true] {
+        if fallback {
+            js_sys::Reflect::set(
+                &store.clone().into(),
+                &JsValue::from_str("getAllRecords"),
+                &JsValue::UNDEFINED,
+            )
+            .unwrap();
+        }
+
+        let records = store
+            .get_all_records(Query::from(KeyRange::lower_bound(2, None).unwrap()).into())
+            .await
+            .unwrap();
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| record.key.clone())
+                .collect::<Vec<_>>(),
+            [2, 3, 4, 5].map(Key::from).to_vec()
+        );
+        assert!(records
+            .iter()
+            .all(|record| record.key == record.primary_key));
+
+        // In reverse order and limited by count
+        let records = store
+            .get_all_records(GetAllOptions {
+                limit: Some(2),
+                direction: Some(CursorDirection::Prev),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].primary_key, Key::from(5));
+        assert_eq!(records[1].primary_key, Key::from(4));
+
+        let value: Value = serde_wasm_bindgen::from_value(records[1].value.clone()).unwrap();
+        assert_eq!(value, serde_json::json!({ "id": 4, "name": "Employee 4" }));
+
+        let primary_keys = store
+            .get_all_keys_with_options(GetAllOptions {
+                limit: Some(3),
+                direction: Some(CursorDirection::Prev),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(primary_keys, [5, 4, 3].map(JsValue::from));
+    }
+
+    transaction.await.unwrap();
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
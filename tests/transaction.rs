@@ -1,5 +1,11 @@
-use idb::{DatabaseEvent, Factory, ObjectStoreParams, TransactionMode, TransactionResult};
+use std::{cell::Cell, future::IntoFuture, rc::Rc, time::Duration};
+
+use idb::{
+    DatabaseEvent, Error, Factory, ObjectStoreParams, TransactionDurability, TransactionMode,
+    TransactionOptions, TransactionResult,
+};
 use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::AbortController;
 
 #[wasm_bindgen_test]
 async fn test_transaction_commit() {
@@ -120,6 +126,116 @@ async fn test_transaction_abort() {
     factory.delete("test").unwrap().await.unwrap();
 }
 
+#[wasm_bindgen_test]
+async fn test_request_timeout_and_cancellation() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        database
+            .create_object_store("store1", ObjectStoreParams::new())
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    // Requests completing before the timeout resolve as usual
+    let transaction = database
+        .transaction(&["store1"], TransactionMode::ReadWrite)
+        .unwrap();
+
+    let store = transaction.object_store("store1").unwrap();
+    let id = store
+        .add(
+            &serde_wasm_bindgen::to_value("hello").unwrap(),
+            Some(&serde_wasm_bindgen::to_value("world").unwrap()),
+        )
+        .unwrap()
+        .await_with_timeout(Duration::from_secs(10))
+        .await;
+
+    assert_eq!(id, Ok(serde_wasm_bindgen::to_value("world").unwrap()));
+    assert_eq!(transaction.await, Ok(TransactionResult::Committed));
+
+    // Requests of a transaction waiting for another (busy) transaction on the same store time out
+    let busy_transaction = database
+        .transaction(&["store1"], TransactionMode::ReadWrite)
+        .unwrap();
+    let busy_store = busy_transaction.object_store("store1").unwrap();
+
+    let transaction = database
+        .transaction(&["store1"], TransactionMode::ReadWrite)
+        .unwrap();
+    let store = transaction.object_store("store1").unwrap();
+
+    let timed_out = Cell::new(false);
+
+    let busy = async {
+        while !timed_out.get() {
+            busy_store.count(None).unwrap().await.unwrap();
+        }
+    };
+
+    let request = async {
+        let result = store
+            .add(
+                &serde_wasm_bindgen::to_value("hi").unwrap(),
+                Some(&serde_wasm_bindgen::to_value("mars").unwrap()),
+            )
+            .unwrap()
+            .await_with_timeout(Duration::from_millis(50))
+            .await;
+
+        timed_out.set(true);
+        result
+    };
+
+    let (_, id) = futures::join!(busy, request);
+
+    assert_eq!(id, Err(Error::TimedOut));
+    assert_eq!(transaction.await, Ok(TransactionResult::Aborted));
+    assert_eq!(busy_transaction.await, Ok(TransactionResult::Committed));
+
+    // Cancelling a request aborts its transaction
+    let controller = AbortController::new().unwrap();
+    controller.abort();
+
+    let transaction = database
+        .transaction(&["store1"], TransactionMode::ReadWrite)
+        .unwrap();
+
+    let store = transaction.object_store("store1").unwrap();
+    let id = store
+        .add(
+            &serde_wasm_bindgen::to_value("bye").unwrap(),
+            Some(&serde_wasm_bindgen::to_value("moon").unwrap()),
+        )
+        .unwrap()
+        .await_with_signal(&controller.signal())
+        .await;
+
+    assert_eq!(id, Err(Error::Cancelled));
+    assert_eq!(transaction.await, Ok(TransactionResult::Aborted));
+
+    let read_transaction = database
+        .transaction(&["store1"], TransactionMode::ReadOnly)
+        .unwrap();
+    let count = read_transaction
+        .object_store("store1")
+        .unwrap()
+        .count(None)
+        .unwrap()
+        .await;
+
+    assert_eq!(count, Ok(1));
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
 #[wasm_bindgen_test]
 async fn test_transaction_error() {
     let factory = Factory::new().unwrap();
@@ -302,3 +418,164 @@ async fn test_transaction_fail_event_loop_control() {
     database.close();
     factory.delete("test").unwrap().await.unwrap();
 }
+
+#[wasm_bindgen_test]
+async fn test_transaction_with_options() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        database
+            .create_object_store("store1", ObjectStoreParams::new())
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    let transaction = database
+        .transaction(&["store1"], TransactionMode::ReadWrite)
+        .unwrap();
+    assert_eq!(transaction.durability(), Ok(TransactionDurability::Default));
+    transaction.await.unwrap();
+
+    let mut options = TransactionOptions::new();
+    options.durability(TransactionDurability::Relaxed);
+
+    let transaction = database
+        .transaction_with_options(&["store1"], TransactionMode::ReadWrite, options)
+        .unwrap();
+    assert_eq!(transaction.mode(), Ok(TransactionMode::ReadWrite));
+
+    let store = transaction.object_store("store1").unwrap();
+    store
+        .add(
+            &serde_wasm_bindgen::to_value("hello").unwrap(),
+            Some(&serde_wasm_bindgen::to_value("world").unwrap()),
+        )
+        .unwrap()
+        .await
+        .unwrap();
+
+    let transaction = transaction.commit().unwrap().into_future();
+
+    // Browsers without support for durability hints report the default durability
+    let durability = transaction.durability().unwrap();
+    assert!(matches!(
+        durability,
+        TransactionDurability::Relaxed | TransactionDurability::Default
+    ));
+
+    assert_eq!(transaction.await, Ok(TransactionResult::Committed));
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_database_with_transaction() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        database
+            .create_object_store("store1", ObjectStoreParams::new())
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    // Closure returning `Ok` commits the transaction
+    let (id, result) = database
+        .with_transaction(
+            &["store1"],
+            TransactionMode::ReadWrite,
+            |transaction| async move {
+                let store = transaction.object_store("store1")?;
+                store
+                    .add(
+                        &serde_wasm_bindgen::to_value("hello").unwrap(),
+                        Some(&serde_wasm_bindgen::to_value("world").unwrap()),
+                    )?
+                    .await
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(id, serde_wasm_bindgen::to_value("world").unwrap());
+    assert_eq!(result, TransactionResult::Committed);
+
+    // Closure returning `Err` aborts the transaction
+    let result: Result<((), TransactionResult), Error> = database
+        .with_transaction(
+            &["store1"],
+            TransactionMode::ReadWrite,
+            |transaction| async move {
+                let store = transaction.object_store("store1")?;
+                store.clear()?.await?;
+
+                Err(Error::CursorFinished)
+            },
+        )
+        .await;
+
+    assert_eq!(result, Err(Error::CursorFinished));
+
+    // Failed requests abort the transaction with their error
+    let result = database
+        .with_transaction(
+            &["store1"],
+            TransactionMode::ReadWrite,
+            |transaction| async move {
+                let store = transaction.object_store("store1")?;
+                store.add(
+                    &serde_wasm_bindgen::to_value("hello").unwrap(),
+                    Some(&serde_wasm_bindgen::to_value("world").unwrap()),
+                )?;
+
+                Ok::<_, Error>(())
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::DomException(_))));
+
+    // Handlers set on the transaction by the closure do not prevent it from resolving
+    let completed = Rc::new(Cell::new(false));
+    let (_transaction, result) = database
+        .with_transaction(&["store1"], TransactionMode::ReadOnly, {
+            let completed = completed.clone();
+            |mut transaction| async move {
+                transaction.on_complete(move |_| completed.set(true));
+                transaction.object_store("store1")?.count(None)?.await?;
+
+                // Returned to keep the `complete` handler alive until the transaction finishes
+                Ok::<_, Error>(transaction)
+            }
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result, TransactionResult::Committed);
+    assert!(completed.get());
+
+    let (count, _) = database
+        .with_transaction(
+            &["store1"],
+            TransactionMode::ReadOnly,
+            |transaction| async move { transaction.object_store("store1")?.count(None)?.await },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(count, 1);
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "serde")]
+
+use idb::{DatabaseEvent, Factory, IndexParams, KeyPath, ObjectStoreParams, TransactionMode};
+use serde_json::Value;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn test_typed_object_store() {
+    let factory = Factory::new().unwrap();
+    factory.delete("test").unwrap().await.unwrap();
+
+    let mut open_request = factory.open("test", Some(1)).unwrap();
+    open_request.on_upgrade_needed(|event| {
+        let database = event.database().unwrap();
+
+        let mut store_params = ObjectStoreParams::new();
+        store_params.auto_increment(true);
+        store_params.key_path(Some(KeyPath::new_single("id")));
+
+        let store = database
+            .create_object_store("employees", store_params)
+            .unwrap();
+
+        let mut index_params = IndexParams::new();
+        index_params.unique(true);
+
+        store
+            .create_index("email", KeyPath::new_single("email"), Some(index_params))
+            .unwrap();
+    });
+
+    let database = open_request.await.unwrap();
+
+    // Add values to store
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadWrite)
+        .unwrap();
+
+    let store = transaction
+        .typed_object_store::<u32, Value>("employees")
+        .unwrap();
+
+    let id1 = store
+        .add(
+            &serde_json::json!({
+                "name": "John Doe",
+                "email": "john@example.com",
+            }),
+            None,
+        )
+        .unwrap()
+        .await
+        .unwrap();
+    let id2 = store
+        .add(
+            &serde_json::json!({
+                "name": "Jane Doe",
+                "email": "jane@example.com",
+            }),
+            None,
+        )
+        .unwrap()
+        .await
+        .unwrap();
+
+    assert_eq!(id1, 1);
+    assert_eq!(id2, 2);
+
+    transaction.commit().unwrap().await.unwrap();
+
+    // Read values back
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadOnly)
+        .unwrap();
+
+    let store = transaction
+        .typed_object_store::<u32, Value>("employees")
+        .unwrap();
+
+    let employee = store.get(&id1).unwrap().await.unwrap().unwrap();
+    assert_eq!(employee["name"], "John Doe");
+    assert_eq!(employee["id"], 1);
+
+    assert_eq!(store.get(&3).unwrap().await, Ok(None));
+
+    let employees = store.get_all(None, None).unwrap().await.unwrap();
+    assert_eq!(employees.len(), 2);
+    assert_eq!(employees[1]["name"], "Jane Doe");
+
+    assert_eq!(
+        store.get_all_keys(None, None).unwrap().await,
+        Ok(vec![1, 2])
+    );
+
+    let index = store.index::<String>("email").unwrap();
+    let employee = index
+        .get(&"jane@example.com".to_string())
+        .unwrap()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(employee["id"], 2);
+
+    assert_eq!(
+        index
+            .get_key::<u32>(&"john@example.com".to_string())
+            .unwrap()
+            .await,
+        Ok(Some(1))
+    );
+    assert_eq!(
+        index
+            .get_key::<u32>(&"jim@example.com".to_string())
+            .unwrap()
+            .await,
+        Ok(None)
+    );
+
+    // Primary keys are ordered by the index's keys
+    assert_eq!(
+        index.get_all_keys::<u32>(None, None).unwrap().await,
+        Ok(vec![2, 1])
+    );
+
+    transaction.await.unwrap();
+
+    // Deserialization errors are surfaced as errors
+    let transaction = database
+        .transaction(&["employees"], TransactionMode::ReadOnly)
+        .unwrap();
+
+    let store = transaction
+        .typed_object_store::<u32, String>("employees")
+        .unwrap();
+
+    let result = store.get(&id1).unwrap().await;
+    assert!(matches!(result, Err(idb::Error::DeserializeFailed(_))));
+
+    transaction.await.unwrap();
+
+    database.close();
+    factory.delete("test").unwrap().await.unwrap();
+}
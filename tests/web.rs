@@ -1,13 +1,23 @@
 #![cfg(target_arch = "wasm32")]
 
+#[cfg(feature = "backup")]
+mod backup;
 mod builder;
+#[cfg(feature = "changes")]
+mod changes;
 mod cursor;
 mod database;
+#[cfg(feature = "derive")]
+mod derive;
+mod error;
 mod factory;
 mod index;
+mod key;
 mod object_store;
 mod open_request;
 mod transaction;
+#[cfg(feature = "serde")]
+mod typed;
 
 use wasm_bindgen_test::wasm_bindgen_test_configure;
 